@@ -88,3 +88,38 @@ bitflags! {
         const CAPTURE_WITH_WAIT_TIMEOUT   = NVML_NVFBC_SESSION_FLAG_CAPTURE_WITH_WAIT_TIMEOUT;
     }
 }
+
+bitflags! {
+    /// Flags that specify the states in which a vGPU / VM may be booted on a
+    /// physical GPU.
+    // Checked against local
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+    pub struct VgpuVmCompatibility: u32 {
+        /// The vGPU / VM can be booted from a cold (powered off) state.
+        const COLD      = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_COLD;
+        /// The vGPU / VM can be resumed from hibernation.
+        const HIBERNATE = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_HIBERNATE;
+        /// The vGPU / VM can be resumed from sleep.
+        const SLEEP     = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_SLEEP;
+        /// The vGPU / VM can be live migrated.
+        const LIVE      = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_LIVE;
+    }
+}
+
+bitflags! {
+    /// Flags that specify the factors limiting vGPU-pGPU compatibility.
+    // Checked against local
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+    pub struct VgpuCompatibilityLimit: u32 {
+        /// Compatibility is limited by the host driver version.
+        const HOST_DRIVER  = nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_HOST_DRIVER;
+        /// Compatibility is limited by the guest driver version.
+        const GUEST_DRIVER = nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_GUEST_DRIVER;
+        /// Compatibility is limited by the GPU hardware.
+        const GPU          = nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_GPU;
+        /// Compatibility is limited by an undefined factor.
+        const OTHER        = nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_OTHER;
+    }
+}
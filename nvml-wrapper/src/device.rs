@@ -10,21 +10,31 @@ use crate::bitmasks::event::EventTypes;
 #[cfg(target_os = "windows")]
 use crate::bitmasks::Behavior;
 
-use crate::enum_wrappers::{bool_from_state, device::*, state_from_bool};
+use crate::enum_wrappers::{
+    bool_from_state,
+    device::*,
+    nv_link::{ErrorCounter, IntDeviceType},
+    state_from_bool,
+};
 
+#[cfg(target_os = "windows")]
+use crate::enums::device::DriverModelTransition;
 use crate::enums::device::{
-    BusType, DeviceArchitecture, FanControlPolicy, GpuLockedClocksSetting, PcieLinkMaxSpeed,
-    PowerSource,
+    BusIdFormat, BusType, DeviceArchitecture, FanControlPolicy, GpuLockedClocksSetting,
+    PcieLinkMaxSpeed, PowerSource, SampleValue, ThermalTarget, UsedGpuMemory,
 };
 use crate::error::nvml_try_count;
 #[cfg(target_os = "linux")]
 use crate::error::NvmlErrorWithSource;
 use crate::error::{nvml_sym, nvml_try, Bits, NvmlError};
+#[cfg(target_os = "linux")]
+use crate::gpu_instance::GpuInstance;
 
 use crate::ffi::bindings::*;
 
 use crate::struct_wrappers::device::*;
 use crate::structs::device::*;
+use crate::structs::nv_link::{LinkErrors, NvLinkThroughput};
 
 use crate::vgpu::VgpuType;
 
@@ -33,11 +43,15 @@ use std::convert::TryInto;
 #[cfg(target_os = "linux")]
 use std::os::raw::c_ulong;
 use std::{
+    collections::BTreeMap,
     convert::TryFrom,
-    ffi::CStr,
+    ffi::{CStr, CString},
     mem,
-    os::raw::{c_int, c_uint, c_ulonglong},
-    ptr,
+    os::raw::{c_char, c_int, c_uint, c_ulonglong},
+    ptr, slice,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use static_assertions::assert_impl_all;
@@ -74,6 +88,8 @@ This means you shouldn't have to worry about calls to `Device` methods returning
 pub struct Device<'nvml> {
     device: nvmlDevice_t,
     nvml: &'nvml Nvml,
+    // Memoized by `.cached_uuid()`
+    uuid: Mutex<Option<String>>,
 }
 
 unsafe impl Send for Device<'_> {}
@@ -81,6 +97,27 @@ unsafe impl Sync for Device<'_> {}
 
 assert_impl_all!(Device: Send, Sync);
 
+/// Renders as `GPU <index>: <name> (<uuid>)`, querying each field from NVML.
+///
+/// Fields that can't be retrieved are rendered as `<unavailable>`.
+impl std::fmt::Display for Device<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNAVAILABLE: &str = "<unavailable>";
+
+        match self.index() {
+            Ok(index) => write!(f, "GPU {}: ", index)?,
+            Err(_) => write!(f, "GPU {}: ", UNAVAILABLE)?,
+        }
+
+        write!(
+            f,
+            "{} ({})",
+            self.name().unwrap_or_else(|_| UNAVAILABLE.into()),
+            self.uuid().unwrap_or_else(|_| UNAVAILABLE.into())
+        )
+    }
+}
+
 impl<'nvml> Device<'nvml> {
     /**
     Create a new `Device` wrapper.
@@ -96,7 +133,11 @@ impl<'nvml> Device<'nvml> {
     // Clippy bug, see https://github.com/rust-lang/rust-clippy/issues/5593
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn new(device: nvmlDevice_t, nvml: &'nvml Nvml) -> Self {
-        Self { device, nvml }
+        Self {
+            device,
+            nvml,
+            uuid: Mutex::new(None),
+        }
     }
 
     /// Access the `Nvml` reference this struct wraps
@@ -140,7 +181,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceClearCpuAffinity")]
     pub fn clear_cpu_affinity(&mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceClearCpuAffinity.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceClearCpuAffinity.as_ref(),
+            "nvmlDeviceClearCpuAffinity",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
@@ -149,7 +193,7 @@ impl<'nvml> Device<'nvml> {
     Gets the root/admin permissions for the target API.
 
     Only root users are able to call functions belonging to restricted APIs. See
-    the documentation for the `RestrictedApi` enum for a list of those functions.
+    the documentation for the `Api` enum for a list of those functions.
 
     Non-root users can be granted access to these APIs through use of
     `.set_api_restricted()`.
@@ -174,7 +218,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (except for AutoBoostedClocks)
     #[doc(alias = "nvmlDeviceGetAPIRestriction")]
     pub fn is_api_restricted(&self, api: Api) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAPIRestriction.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAPIRestriction.as_ref(),
+            "nvmlDeviceGetAPIRestriction",
+        )?;
 
         unsafe {
             let mut restricted_state: nvmlEnableState_t = mem::zeroed();
@@ -208,7 +255,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetApplicationsClock")]
     pub fn applications_clock(&self, clock_type: Clock) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetApplicationsClock.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetApplicationsClock.as_ref(),
+            "nvmlDeviceGetApplicationsClock",
+        )?;
 
         unsafe {
             let mut clock: c_uint = mem::zeroed();
@@ -246,7 +296,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetAutoBoostedClocksEnabled")]
     pub fn auto_boosted_clocks_enabled(&self) -> Result<AutoBoostClocksEnabledInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAutoBoostedClocksEnabled.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAutoBoostedClocksEnabled.as_ref(),
+            "nvmlDeviceGetAutoBoostedClocksEnabled",
+        )?;
 
         unsafe {
             let mut is_enabled: nvmlEnableState_t = mem::zeroed();
@@ -283,7 +336,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetBAR1MemoryInfo")]
     pub fn bar1_memory_info(&self) -> Result<BAR1MemoryInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetBAR1MemoryInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetBAR1MemoryInfo.as_ref(),
+            "nvmlDeviceGetBAR1MemoryInfo",
+        )?;
 
         unsafe {
             let mut mem_info: nvmlBAR1Memory_t = mem::zeroed();
@@ -316,7 +372,10 @@ impl<'nvml> Device<'nvml> {
         size: usize,
         scope: nvmlAffinityScope_t,
     ) -> Result<Vec<c_ulong>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMemoryAffinity.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMemoryAffinity.as_ref(),
+            "nvmlDeviceGetMemoryAffinity",
+        )?;
 
         unsafe {
             if size == 0 {
@@ -365,7 +424,38 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetBoardId")]
     pub fn board_id(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetBoardId.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetBoardId.as_ref(),
+            "nvmlDeviceGetBoardId",
+        )?;
+
+        unsafe {
+            let mut id: c_uint = mem::zeroed();
+            nvml_try(sym(self.device, &mut id))?;
+
+            Ok(id)
+        }
+    }
+
+    /**
+    Gets the unique identifier of this `Device`'s module on its baseboard.
+
+    For non-baseboard products this ID is always 0.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `Unknown`, on any unexpected error
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetModuleId")]
+    pub fn module_id(&self) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetModuleId.as_ref(),
+            "nvmlDeviceGetModuleId",
+        )?;
 
         unsafe {
             let mut id: c_uint = mem::zeroed();
@@ -375,6 +465,37 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the C2C (chip-to-chip) mode information for this `Device`.
+
+    C2C refers to the NVLink-C2C interconnect that coherently links the GPU to
+    the CPU on Grace-based systems.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetC2cModeInfoV")]
+    pub fn c2c_mode_info(&self) -> Result<C2cModeInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetC2cModeInfoV.as_ref(),
+            "nvmlDeviceGetC2cModeInfoV",
+        )?;
+
+        unsafe {
+            let mut info: nvmlC2cModeInfo_v1_t = mem::zeroed();
+            nvml_try(sym(self.device, &mut info))?;
+
+            Ok(info.into())
+        }
+    }
+
     /**
     Gets the NUMA node ID for this `Device` (if within a NUMA node).
 
@@ -392,7 +513,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetNumaNodeId")]
     pub fn numa_node_id(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetNumaNodeId.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetNumaNodeId.as_ref(),
+            "nvmlDeviceGetNumaNodeId",
+        )?;
 
         unsafe {
             let mut id: c_uint = mem::zeroed();
@@ -405,27 +529,34 @@ impl<'nvml> Device<'nvml> {
     /**
     Gets the brand of this `Device`.
 
-    See the `Brand` enum for documentation of possible values.
+    See the `Brand` enum for documentation of possible values. Brand values that
+    this wrapper does not recognize (most likely brands introduced in newer
+    drivers) are reported as `Brand::Unknown` rather than as an error.
 
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
     * `InvalidArg`, if this `Device` is invalid
     * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
-    * `UnexpectedVariant`, check that error's docs for more info
     * `Unknown`, on any unexpected error
     */
     // Checked against local nvml.h
     // Tested
     #[doc(alias = "nvmlDeviceGetBrand")]
     pub fn brand(&self) -> Result<Brand, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetBrand.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetBrand.as_ref(),
+            "nvmlDeviceGetBrand",
+        )?;
 
         unsafe {
             let mut brand: nvmlBrandType_t = mem::zeroed();
             nvml_try(sym(self.device, &mut brand))?;
 
-            Brand::try_from(brand)
+            match Brand::try_from(brand) {
+                Err(NvmlError::UnexpectedVariant(_)) => Ok(Brand::Unknown),
+                result => result,
+            }
         }
     }
 
@@ -451,7 +582,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetBridgeChipInfo")]
     pub fn bridge_chip_info(&self) -> Result<BridgeChipHierarchy, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetBridgeChipInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetBridgeChipInfo.as_ref(),
+            "nvmlDeviceGetBridgeChipInfo",
+        )?;
 
         unsafe {
             let mut info: nvmlBridgeChipHierarchy_t = mem::zeroed();
@@ -462,7 +596,12 @@ impl<'nvml> Device<'nvml> {
     }
 
     /**
-    Gets this `Device`'s current clock speed for the given `Clock` type and `ClockId`.
+    Gets this `Device`'s clock speed for the given `Clock` type and `ClockId`.
+
+    The `ClockId` selects which value is returned: the current clock, the
+    target or default application clock, or the OEM-defined boost maximum.
+    This covers what `.clock_info()`, `.applications_clock()` and
+    `.default_applications_clock()` return individually.
 
     # Errors
 
@@ -480,7 +619,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (except for CustomerMaxBoost)
     #[doc(alias = "nvmlDeviceGetClock")]
     pub fn clock(&self, clock_type: Clock, clock_id: ClockId) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetClock.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetClock.as_ref(),
+            "nvmlDeviceGetClock",
+        )?;
 
         unsafe {
             let mut clock: c_uint = mem::zeroed();
@@ -517,7 +659,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetMaxCustomerBoostClock")]
     pub fn max_customer_boost_clock(&self, clock_type: Clock) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMaxCustomerBoostClock.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMaxCustomerBoostClock.as_ref(),
+            "nvmlDeviceGetMaxCustomerBoostClock",
+        )?;
 
         unsafe {
             let mut clock: c_uint = mem::zeroed();
@@ -544,7 +689,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetComputeMode")]
     pub fn compute_mode(&self) -> Result<ComputeMode, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetComputeMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetComputeMode.as_ref(),
+            "nvmlDeviceGetComputeMode",
+        )?;
 
         unsafe {
             let mut mode: nvmlComputeMode_t = mem::zeroed();
@@ -569,7 +717,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetCudaComputeCapability")]
     pub fn cuda_compute_capability(&self) -> Result<CudaComputeCapability, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetCudaComputeCapability.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCudaComputeCapability.as_ref(),
+            "nvmlDeviceGetCudaComputeCapability",
+        )?;
 
         unsafe {
             let mut major: c_int = mem::zeroed();
@@ -581,6 +732,21 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets this `Device`'s CUDA compute capability as a target architecture
+    string, e.g. `sm_80`.
+
+    Useful for selecting PTX or cubin targets. See
+    `CudaComputeCapability.sm_arch_string()`.
+
+    # Errors
+
+    Same as `.cuda_compute_capability()`.
+    */
+    pub fn sm_arch_string(&self) -> Result<String, NvmlError> {
+        Ok(self.cuda_compute_capability()?.sm_arch_string())
+    }
+
     /**
     Gets this `Device`'s current clock speed for the given `Clock` type.
 
@@ -600,7 +766,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetClockInfo")]
     pub fn clock_info(&self, clock_type: Clock) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetClockInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetClockInfo.as_ref(),
+            "nvmlDeviceGetClockInfo",
+        )?;
 
         unsafe {
             let mut clock: c_uint = mem::zeroed();
@@ -611,6 +780,94 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets this `Device`'s current video encoder/decoder clock speed in MHz.
+
+    Shorthand for `.clock_info(Clock::Video)`. NVML doesn't report separate
+    clocks for the JPEG or OFA engines.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` cannot report its video clock
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Fermi or newer fully supported devices.
+    */
+    pub fn video_clock(&self) -> Result<u32, NvmlError> {
+        self.clock_info(Clock::Video)
+    }
+
+    /**
+    Gets this `Device`'s current clock speeds for every `Clock` type.
+
+    Clocks that this `Device` cannot report are `None` rather than failing the
+    whole call.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Fermi or newer fully supported devices.
+    */
+    pub fn all_clocks(&self) -> Result<CurrentClocks, NvmlError> {
+        let clock = |clock_type| match self.clock_info(clock_type) {
+            Ok(clock) => Ok(Some(clock)),
+            Err(NvmlError::NotSupported) => Ok(None),
+            Err(e) => Err(e),
+        };
+
+        Ok(CurrentClocks {
+            graphics: clock(Clock::Graphics)?,
+            sm: clock(Clock::SM)?,
+            memory: clock(Clock::Memory)?,
+            video: clock(Clock::Video)?,
+        })
+    }
+
+    /**
+    Checks whether adaptive clocking is enabled for this `Device`.
+
+    When enabled, the `Device` manages its clocks automatically, so clock
+    readings may vary even under a steady workload.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetAdaptiveClockInfoStatus")]
+    pub fn adaptive_clocking_enabled(&self) -> Result<bool, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAdaptiveClockInfoStatus.as_ref(),
+            "nvmlDeviceGetAdaptiveClockInfoStatus",
+        )?;
+
+        unsafe {
+            let mut status: c_uint = mem::zeroed();
+            nvml_try(sym(self.device, &mut status))?;
+
+            match status {
+                NVML_ADAPTIVE_CLOCKING_INFO_STATUS_ENABLED => Ok(true),
+                NVML_ADAPTIVE_CLOCKING_INFO_STATUS_DISABLED => Ok(false),
+                _ => Err(NvmlError::UnexpectedVariant(status)),
+            }
+        }
+    }
+
     /**
     Gets information about processes with a compute context running on this `Device`.
 
@@ -633,6 +890,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetComputeRunningProcesses_v3
                 .as_ref(),
+            "nvmlDeviceGetComputeRunningProcesses_v3",
         )?;
 
         unsafe {
@@ -659,6 +917,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetMPSComputeRunningProcesses_v3
                 .as_ref(),
+            "nvmlDeviceGetMPSComputeRunningProcesses_v3",
         )?;
 
         unsafe {
@@ -693,6 +952,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetMPSComputeRunningProcesses_v3
                 .as_ref(),
+            "nvmlDeviceGetMPSComputeRunningProcesses_v3",
         )?;
 
         unsafe {
@@ -717,6 +977,9 @@ impl<'nvml> Device<'nvml> {
     with an active context). Graphics applications (OpenGL, DirectX) won't be counted by this
     function.
 
+    No process information is allocated or copied, so this is a cheap way to check
+    whether anything is using this `Device` at all.
+
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
@@ -724,7 +987,7 @@ impl<'nvml> Device<'nvml> {
     * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
     * `Unknown`, on any unexpected error
     */
-    // Tested as part of `.running_compute_processes()`
+    // Tested
     #[doc(alias = "nvmlDeviceGetComputeRunningProcesses_v3")]
     pub fn running_compute_processes_count(&self) -> Result<u32, NvmlError> {
         let sym = nvml_sym(
@@ -732,6 +995,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetComputeRunningProcesses_v3
                 .as_ref(),
+            "nvmlDeviceGetComputeRunningProcesses_v3",
         )?;
 
         unsafe {
@@ -769,6 +1033,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetComputeRunningProcesses_v2
                 .as_ref(),
+            "nvmlDeviceGetComputeRunningProcesses_v2",
         )?;
 
         unsafe {
@@ -811,6 +1076,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetComputeRunningProcesses_v2
                 .as_ref(),
+            "nvmlDeviceGetComputeRunningProcesses_v2",
         )?;
 
         unsafe {
@@ -857,7 +1123,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetCpuAffinity")]
     pub fn cpu_affinity(&self, size: usize) -> Result<Vec<c_ulong>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetCpuAffinity.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCpuAffinity.as_ref(),
+            "nvmlDeviceGetCpuAffinity",
+        )?;
 
         unsafe {
             if size == 0 {
@@ -882,12 +1151,16 @@ impl<'nvml> Device<'nvml> {
     * `InvalidArg`, if confidential compute state is invalid
     */
     pub fn check_confidential_compute_status(&self) -> Result<bool, NvmlError> {
-        let cc_state_sym = nvml_sym(self.nvml.lib.nvmlSystemGetConfComputeState.as_ref())?;
+        let cc_state_sym = nvml_sym(
+            self.nvml.lib.nvmlSystemGetConfComputeState.as_ref(),
+            "nvmlSystemGetConfComputeState",
+        )?;
         let cc_gpus_ready_sym = nvml_sym(
             self.nvml
                 .lib
                 .nvmlSystemGetConfComputeGpusReadyState
                 .as_ref(),
+            "nvmlSystemGetConfComputeGpusReadyState",
         )?;
 
         unsafe {
@@ -907,26 +1180,20 @@ impl<'nvml> Device<'nvml> {
     }
 
     /**
-    Gets the confidential compute state for this `Device`.
+    Gets whether the GPUs are ready to accept confidential compute client
+    requests.
+
+    Unrecognized values are reported as not ready.
+
     # Errors
     * `Uninitialized`, if the library has not been successfully initialized
     * `InvalidArg`, if device is invalid or memory is NULL
     * `NotSupported`, if this query is not supported by the device
     */
-    #[doc(alias = "nvmlDeviceGetConfComputeGpusReadyState")]
+    #[deprecated(note = "use `Device.is_confidential_compute_ready()`")]
+    #[doc(alias = "nvmlSystemGetConfComputeGpusReadyState")]
     pub fn get_confidential_compute_state(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(
-            self.nvml
-                .lib
-                .nvmlSystemGetConfComputeGpusReadyState
-                .as_ref(),
-        )?;
-
-        unsafe {
-            let mut is_accepting_work: u32 = 0;
-            nvml_try(sym(&mut is_accepting_work))?;
-            Ok(is_accepting_work == NVML_CC_ACCEPTING_CLIENT_REQUESTS_TRUE)
-        }
+        Ok(self.is_confidential_compute_ready()? == ConfidentialComputeReadyState::Ready)
     }
 
     /**
@@ -937,12 +1204,16 @@ impl<'nvml> Device<'nvml> {
     * `NotSupported`, if this query is not supported by the device
     */
     #[doc(alias = "nvmlDeviceSetConfComputeState")]
-    pub fn set_confidential_compute_state(&self, is_accepting_work: bool) -> Result<(), NvmlError> {
+    pub fn set_confidential_compute_state(
+        &mut self,
+        is_accepting_work: bool,
+    ) -> Result<(), NvmlError> {
         let sym = nvml_sym(
             self.nvml
                 .lib
                 .nvmlSystemSetConfComputeGpusReadyState
                 .as_ref(),
+            "nvmlSystemSetConfComputeGpusReadyState",
         )?;
 
         unsafe {
@@ -964,7 +1235,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceSetConfComputeSettings")]
     pub fn is_cc_enabled(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlSystemGetConfComputeSettings.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlSystemGetConfComputeSettings.as_ref(),
+            "nvmlSystemGetConfComputeSettings",
+        )?;
 
         unsafe {
             let mut settings: nvmlSystemConfComputeSettings_t = mem::zeroed();
@@ -989,7 +1263,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlSystemGetConfComputeSettings")]
     pub fn is_multi_gpu_protected_pcie_enabled(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlSystemGetConfComputeSettings.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlSystemGetConfComputeSettings.as_ref(),
+            "nvmlSystemGetConfComputeSettings",
+        )?;
 
         unsafe {
             let mut settings: nvmlSystemConfComputeSettings_t = mem::zeroed();
@@ -1014,7 +1291,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlSystemGetConfComputeSettings")]
     pub fn is_cc_dev_mode_enabled(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlSystemGetConfComputeSettings.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlSystemGetConfComputeSettings.as_ref(),
+            "nvmlSystemGetConfComputeSettings",
+        )?;
 
         unsafe {
             let mut settings: nvmlSystemConfComputeSettings_t = mem::zeroed();
@@ -1036,7 +1316,10 @@ impl<'nvml> Device<'nvml> {
     pub fn get_confidential_compute_capabilities(
         &self,
     ) -> Result<ConfidentialComputeCapabilities, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlSystemGetConfComputeCapabilities.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlSystemGetConfComputeCapabilities.as_ref(),
+            "nvmlSystemGetConfComputeCapabilities",
+        )?;
 
         unsafe {
             let mut capabilities: nvmlConfComputeSystemCaps_t = mem::zeroed();
@@ -1046,7 +1329,7 @@ impl<'nvml> Device<'nvml> {
                 NVML_CC_SYSTEM_CPU_CAPS_NONE => ConfidentialComputeCpuCapabilities::None,
                 NVML_CC_SYSTEM_CPU_CAPS_AMD_SEV => ConfidentialComputeCpuCapabilities::AmdSev,
                 NVML_CC_SYSTEM_CPU_CAPS_INTEL_TDX => ConfidentialComputeCpuCapabilities::IntelTdx,
-                _ => return Err(NvmlError::Unknown),
+                v => ConfidentialComputeCpuCapabilities::Unknown(v),
             };
 
             let gpus_caps = match capabilities.gpusCaps {
@@ -1054,7 +1337,7 @@ impl<'nvml> Device<'nvml> {
                 NVML_CC_SYSTEM_GPUS_CC_NOT_CAPABLE => {
                     ConfidentialComputeGpuCapabilities::NotCapable
                 }
-                _ => return Err(NvmlError::Unknown),
+                v => ConfidentialComputeGpuCapabilities::Unknown(v),
             };
 
             Ok(ConfidentialComputeCapabilities {
@@ -1065,17 +1348,11 @@ impl<'nvml> Device<'nvml> {
     }
 
     /**
-    Fetches the confidential compute attestation report for this [`Device`].
-
-    This method retrieves a comprehensive attestation report from the device, which includes:
-    - A 32-byte nonce
-    - The attestation report size (as big-endian bytes)
-    - The attestation report data (up to 8192 bytes)
-    - A flag indicating if CEC attestation is present (as big-endian bytes)
-    - The CEC attestation report size (as big-endian bytes)
-    - The CEC attestation report data (up to 4096 bytes)
+    Gets the system-wide confidential compute state: the environment, whether
+    the confidential compute feature is enabled, and the devtools mode.
 
-    The returned vector contains all these components concatenated together in the order listed above.
+    Values that this wrapper does not recognize are reported via the `Unknown`
+    variant of the corresponding enum rather than as an error.
 
     # Errors
 
@@ -1084,95 +1361,322 @@ impl<'nvml> Device<'nvml> {
     * `NotSupported`, if this query is not supported by the device
     * `Unknown`, on any unexpected error
     */
-    #[doc(alias = "nvmlDeviceGetAttestationReport")]
-    pub fn confidential_compute_gpu_attestation_report(
-        &self,
-        nonce: [u8; NVML_CC_GPU_CEC_NONCE_SIZE as usize],
-    ) -> Result<ConfidentialComputeGpuAttestationReport, NvmlError> {
+    #[doc(alias = "nvmlSystemGetConfComputeState")]
+    pub fn confidential_compute_state(&self) -> Result<ConfidentialComputeState, NvmlError> {
         let sym = nvml_sym(
-            self.nvml
-                .lib
-                .nvmlDeviceGetConfComputeGpuAttestationReport
-                .as_ref(),
+            self.nvml.lib.nvmlSystemGetConfComputeState.as_ref(),
+            "nvmlSystemGetConfComputeState",
         )?;
 
         unsafe {
-            let mut report: nvmlConfComputeGpuAttestationReport_st = mem::zeroed();
-            report.nonce = nonce;
+            let mut state: nvmlConfComputeSystemState_t = mem::zeroed();
+            nvml_try(sym(&mut state))?;
 
-            nvml_try(sym(self.device, &mut report))?;
+            let environment = match state.environment {
+                NVML_CC_SYSTEM_ENVIRONMENT_UNAVAILABLE => {
+                    ConfidentialComputeEnvironment::Unavailable
+                }
+                NVML_CC_SYSTEM_ENVIRONMENT_SIM => ConfidentialComputeEnvironment::Sim,
+                NVML_CC_SYSTEM_ENVIRONMENT_PROD => ConfidentialComputeEnvironment::Prod,
+                v => ConfidentialComputeEnvironment::Unknown(v),
+            };
 
-            let is_cec_attestation_report_present = report.isCecAttestationReportPresent == 1;
-            Ok(ConfidentialComputeGpuAttestationReport {
-                attestation_report_size: report.attestationReportSize,
-                attestation_report: report.attestationReport
-                    [..report.attestationReportSize as usize]
-                    .to_vec(),
-                is_cec_attestation_report_present,
-                cec_attestation_report_size: report.cecAttestationReportSize,
-                cec_attestation_report: report.cecAttestationReport
-                    [..report.cecAttestationReportSize as usize]
-                    .to_vec(),
+            let cc_feature = match state.ccFeature {
+                NVML_CC_SYSTEM_FEATURE_DISABLED => ConfidentialComputeFeature::Disabled,
+                NVML_CC_SYSTEM_FEATURE_ENABLED => ConfidentialComputeFeature::Enabled,
+                v => ConfidentialComputeFeature::Unknown(v),
+            };
+
+            let dev_tools_mode = match state.devToolsMode {
+                NVML_CC_SYSTEM_DEVTOOLS_MODE_OFF => ConfidentialComputeDevToolsMode::Off,
+                NVML_CC_SYSTEM_DEVTOOLS_MODE_ON => ConfidentialComputeDevToolsMode::On,
+                v => ConfidentialComputeDevToolsMode::Unknown(v),
+            };
+
+            Ok(ConfidentialComputeState {
+                environment,
+                cc_feature,
+                dev_tools_mode,
             })
         }
     }
 
     /**
-    Gets the confidential compute GPU certificate for this `Device`.
+    Gets whether the GPUs are ready to accept confidential compute client
+    requests.
+
+    This mirrors `nvidia-smi conf-compute -grs`. Unrecognized values are
+    reported as `ConfidentialComputeReadyState::Unknown`.
 
     # Errors
 
-    * `Uninitialized` if the library has not been successfully initialized
-    * `InvalidArg` if device is invalid or memory is NULL
-    * `NotSupported` if this query is not supported by the device
-    * `Unknown` on any unexpected error
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if device is invalid or memory is NULL
+    * `NotSupported`, if this query is not supported by the device
+    * `Unknown`, on any unexpected error
     */
-    pub fn confidential_compute_gpu_certificate(
+    #[doc(alias = "nvmlSystemGetConfComputeGpusReadyState")]
+    pub fn is_confidential_compute_ready(
         &self,
-    ) -> Result<ConfidentialComputeGpuCertificate, NvmlError> {
+    ) -> Result<ConfidentialComputeReadyState, NvmlError> {
         let sym = nvml_sym(
             self.nvml
                 .lib
-                .nvmlDeviceGetConfComputeGpuCertificate
+                .nvmlSystemGetConfComputeGpusReadyState
                 .as_ref(),
+            "nvmlSystemGetConfComputeGpusReadyState",
         )?;
 
         unsafe {
-            let mut certificate_chain: nvmlConfComputeGpuCertificate_t = mem::zeroed();
-            nvml_try(sym(self.device, &mut certificate_chain))?;
+            let mut is_accepting_work: c_uint = 0;
+            nvml_try(sym(&mut is_accepting_work))?;
 
-            Ok(ConfidentialComputeGpuCertificate {
-                cert_chain_size: certificate_chain.certChainSize,
-                attestation_cert_chain_size: certificate_chain.attestationCertChainSize,
-                cert_chain: certificate_chain.certChain[..certificate_chain.certChainSize as usize]
-                    .to_vec(),
-                attestation_cert_chain: certificate_chain.attestationCertChain
-                    [..certificate_chain.attestationCertChainSize as usize]
-                    .to_vec(),
+            Ok(match is_accepting_work {
+                NVML_CC_ACCEPTING_CLIENT_REQUESTS_TRUE => ConfidentialComputeReadyState::Ready,
+                NVML_CC_ACCEPTING_CLIENT_REQUESTS_FALSE => ConfidentialComputeReadyState::NotReady,
+                v => ConfidentialComputeReadyState::Unknown(v),
             })
         }
     }
 
     /**
-    Gets the current PCIe link generation.
+    Gets the confidential compute key rotation threshold, expressed as the
+    maximum attacker advantage.
 
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
-    * `InvalidArg`, if this `Device` is invalid
-    * `NotSupported`, if PCIe link information is not available
-    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `InvalidArg`, if device is invalid or memory is NULL
+    * `NotSupported`, if this query is not supported by the device
     * `Unknown`, on any unexpected error
 
     # Device Support
 
-    Supports Fermi or newer fully supported devices.
+    Supports Hopper and newer fully supported devices.
     */
-    // Checked against local
-    // Tested
-    #[doc(alias = "nvmlDeviceGetCurrPcieLinkGeneration")]
-    pub fn current_pcie_link_gen(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetCurrPcieLinkGeneration.as_ref())?;
+    #[doc(alias = "nvmlSystemGetConfComputeKeyRotationThresholdInfo")]
+    pub fn confidential_compute_key_rotation_threshold(
+        &self,
+    ) -> Result<ConfidentialComputeKeyRotationThreshold, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlSystemGetConfComputeKeyRotationThresholdInfo
+                .as_ref(),
+            "nvmlSystemGetConfComputeKeyRotationThresholdInfo",
+        )?;
+
+        unsafe {
+            let mut info: nvmlConfComputeGetKeyRotationThresholdInfo_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(ConfComputeGetKeyRotationThresholdInfo, 1), as detailed in nvml.h
+            info.version = (std::mem::size_of::<nvmlConfComputeGetKeyRotationThresholdInfo_v1_t>()
+                | (1_usize << 24_usize)) as u32;
+            nvml_try(sym(&mut info))?;
+
+            Ok(ConfidentialComputeKeyRotationThreshold {
+                max_attacker_advantage: info.attackerAdvantage,
+            })
+        }
+    }
+
+    /**
+    Sets the confidential compute key rotation threshold.
+
+    `threshold.max_attacker_advantage` should be in the range from
+    `NVML_CC_KEY_ROTATION_THRESHOLD_ATTACKER_ADVANTAGE_MIN` to
+    `NVML_CC_KEY_ROTATION_THRESHOLD_ATTACKER_ADVANTAGE_MAX` (50 to 65). The
+    default value is 60.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if device is invalid or `max_attacker_advantage` is out of range
+    * `NotSupported`, if this query is not supported by the device
+    * `Unknown`, if the confidential compute GPU ready state is enabled, or on
+      any unexpected error
+
+    # Device Support
+
+    Supports Hopper and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlSystemSetConfComputeKeyRotationThresholdInfo")]
+    pub fn set_confidential_compute_key_rotation_threshold(
+        &mut self,
+        threshold: ConfidentialComputeKeyRotationThreshold,
+    ) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlSystemSetConfComputeKeyRotationThresholdInfo
+                .as_ref(),
+            "nvmlSystemSetConfComputeKeyRotationThresholdInfo",
+        )?;
+
+        unsafe {
+            let mut info: nvmlConfComputeSetKeyRotationThresholdInfo_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(ConfComputeSetKeyRotationThresholdInfo, 1), as detailed in nvml.h
+            info.version = (std::mem::size_of::<nvmlConfComputeSetKeyRotationThresholdInfo_v1_t>()
+                | (1_usize << 24_usize)) as u32;
+            info.maxAttackerAdvantage = threshold.max_attacker_advantage;
+
+            nvml_try(sym(&mut info))
+        }
+    }
+
+    /**
+    Sets the confidential compute unprotected memory size for this `Device`,
+    in KiB.
+
+    The unprotected region is the memory that stays accessible to the CPU when
+    confidential compute is enabled, such as the bounce buffers used for
+    transfers. Changing it requires root/admin privileges.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this query is not supported by the device
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Supports Linux and Windows in TCC mode.
+    */
+    #[doc(alias = "nvmlDeviceSetConfComputeUnprotectedMemSize")]
+    pub fn set_confidential_compute_unprotected_mem_size(
+        &mut self,
+        size_kib: u64,
+    ) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlDeviceSetConfComputeUnprotectedMemSize
+                .as_ref(),
+            "nvmlDeviceSetConfComputeUnprotectedMemSize",
+        )?;
+
+        unsafe { nvml_try(sym(self.device, size_kib)) }
+    }
+
+    /**
+    Fetches the confidential compute attestation report for this [`Device`].
+
+    This method retrieves a comprehensive attestation report from the device, which includes:
+    - A 32-byte nonce
+    - The attestation report size (as big-endian bytes)
+    - The attestation report data (up to 8192 bytes)
+    - A flag indicating if CEC attestation is present (as big-endian bytes)
+    - The CEC attestation report size (as big-endian bytes)
+    - The CEC attestation report data (up to 4096 bytes)
+
+    The returned vector contains all these components concatenated together in the order listed above.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if device is invalid or memory is NULL
+    * `NotSupported`, if this query is not supported by the device
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetAttestationReport")]
+    pub fn confidential_compute_gpu_attestation_report(
+        &self,
+        nonce: [u8; NVML_CC_GPU_CEC_NONCE_SIZE as usize],
+    ) -> Result<ConfidentialComputeGpuAttestationReport, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlDeviceGetConfComputeGpuAttestationReport
+                .as_ref(),
+            "nvmlDeviceGetConfComputeGpuAttestationReport",
+        )?;
+
+        unsafe {
+            let mut report: nvmlConfComputeGpuAttestationReport_st = mem::zeroed();
+            report.nonce = nonce;
+
+            nvml_try(sym(self.device, &mut report))?;
+
+            let is_cec_attestation_report_present = report.isCecAttestationReportPresent == 1;
+            Ok(ConfidentialComputeGpuAttestationReport {
+                attestation_report_size: report.attestationReportSize,
+                attestation_report: report.attestationReport
+                    [..report.attestationReportSize as usize]
+                    .to_vec(),
+                is_cec_attestation_report_present,
+                cec_attestation_report_size: report.cecAttestationReportSize,
+                cec_attestation_report: report.cecAttestationReport
+                    [..report.cecAttestationReportSize as usize]
+                    .to_vec(),
+            })
+        }
+    }
+
+    /**
+    Gets the confidential compute GPU certificate for this `Device`.
+
+    # Errors
+
+    * `Uninitialized` if the library has not been successfully initialized
+    * `InvalidArg` if device is invalid or memory is NULL
+    * `NotSupported` if this query is not supported by the device
+    * `Unknown` on any unexpected error
+    */
+    pub fn confidential_compute_gpu_certificate(
+        &self,
+    ) -> Result<ConfidentialComputeGpuCertificate, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlDeviceGetConfComputeGpuCertificate
+                .as_ref(),
+            "nvmlDeviceGetConfComputeGpuCertificate",
+        )?;
+
+        unsafe {
+            let mut certificate_chain: nvmlConfComputeGpuCertificate_t = mem::zeroed();
+            nvml_try(sym(self.device, &mut certificate_chain))?;
+
+            Ok(ConfidentialComputeGpuCertificate {
+                cert_chain_size: certificate_chain.certChainSize,
+                attestation_cert_chain_size: certificate_chain.attestationCertChainSize,
+                cert_chain: certificate_chain.certChain[..certificate_chain.certChainSize as usize]
+                    .to_vec(),
+                attestation_cert_chain: certificate_chain.attestationCertChain
+                    [..certificate_chain.attestationCertChainSize as usize]
+                    .to_vec(),
+            })
+        }
+    }
+
+    /**
+    Gets the current PCIe link generation.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if PCIe link information is not available
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Fermi or newer fully supported devices.
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetCurrPcieLinkGeneration")]
+    pub fn current_pcie_link_gen(&self) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCurrPcieLinkGeneration.as_ref(),
+            "nvmlDeviceGetCurrPcieLinkGeneration",
+        )?;
 
         unsafe {
             let mut link_gen: c_uint = mem::zeroed();
@@ -1202,7 +1706,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetCurrPcieLinkWidth")]
     pub fn current_pcie_link_width(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetCurrPcieLinkWidth.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCurrPcieLinkWidth.as_ref(),
+            "nvmlDeviceGetCurrPcieLinkWidth",
+        )?;
 
         unsafe {
             let mut link_width: c_uint = mem::zeroed();
@@ -1231,7 +1738,89 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetDecoderUtilization")]
     pub fn decoder_utilization(&self) -> Result<UtilizationInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetDecoderUtilization.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDecoderUtilization.as_ref(),
+            "nvmlDeviceGetDecoderUtilization",
+        )?;
+
+        unsafe {
+            let mut utilization: c_uint = mem::zeroed();
+            let mut sampling_period: c_uint = mem::zeroed();
+
+            nvml_try(sym(self.device, &mut utilization, &mut sampling_period))?;
+
+            Ok(UtilizationInfo {
+                utilization,
+                sampling_period,
+            })
+        }
+    }
+
+    /**
+    Gets the current utilization and sampling size (sampling size in μs) for the JPEG engine.
+
+    Querying this on MIG-enabled devices is not currently supported.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` lacks this engine or does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Turing or newer fully supported devices.
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetJpgUtilization")]
+    pub fn jpg_utilization(&self) -> Result<UtilizationInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetJpgUtilization.as_ref(),
+            "nvmlDeviceGetJpgUtilization",
+        )?;
+
+        unsafe {
+            let mut utilization: c_uint = mem::zeroed();
+            let mut sampling_period: c_uint = mem::zeroed();
+
+            nvml_try(sym(self.device, &mut utilization, &mut sampling_period))?;
+
+            Ok(UtilizationInfo {
+                utilization,
+                sampling_period,
+            })
+        }
+    }
+
+    /**
+    Gets the current utilization and sampling size (sampling size in μs) for the OFA
+    (Optical Flow Accelerator).
+
+    Querying this on MIG-enabled devices is not currently supported.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` lacks this engine or does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Turing or newer fully supported devices.
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetOfaUtilization")]
+    pub fn ofa_utilization(&self) -> Result<UtilizationInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetOfaUtilization.as_ref(),
+            "nvmlDeviceGetOfaUtilization",
+        )?;
 
         unsafe {
             let mut utilization: c_uint = mem::zeroed();
@@ -1263,7 +1852,10 @@ impl<'nvml> Device<'nvml> {
     // tested
     #[doc(alias = "nvmlDeviceGetFBCStats")]
     pub fn fbc_stats(&self) -> Result<FbcStats, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFBCStats.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFBCStats.as_ref(),
+            "nvmlDeviceGetFBCStats",
+        )?;
 
         unsafe {
             let mut fbc_stats: nvmlFBCStats_t = mem::zeroed();
@@ -1297,7 +1889,10 @@ impl<'nvml> Device<'nvml> {
     // tested
     #[doc(alias = "nvmlDeviceGetFBCSessions")]
     pub fn fbc_sessions_info(&self) -> Result<Vec<FbcSessionInfo>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFBCSessions.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFBCSessions.as_ref(),
+            "nvmlDeviceGetFBCSessions",
+        )?;
 
         unsafe {
             let mut count: c_uint = match self.fbc_session_count()? {
@@ -1325,7 +1920,10 @@ impl<'nvml> Device<'nvml> {
     // tested as part of the above
     #[doc(alias = "nvmlDeviceGetFBCSessions")]
     pub fn fbc_session_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFBCSessions.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFBCSessions.as_ref(),
+            "nvmlDeviceGetFBCSessions",
+        )?;
 
         unsafe {
             let mut count: c_uint = 0;
@@ -1351,7 +1949,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetAttributes_v2")]
     pub fn attributes(&self) -> Result<DeviceAttributes, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAttributes_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAttributes_v2.as_ref(),
+            "nvmlDeviceGetAttributes_v2",
+        )?;
 
         unsafe {
             let mut attrs: nvmlDeviceAttributes_t = mem::zeroed();
@@ -1381,7 +1982,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetDefaultApplicationsClock")]
     pub fn default_applications_clock(&self, clock_type: Clock) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetDefaultApplicationsClock.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDefaultApplicationsClock.as_ref(),
+            "nvmlDeviceGetDefaultApplicationsClock",
+        )?;
 
         unsafe {
             let mut clock: c_uint = mem::zeroed();
@@ -1401,7 +2005,10 @@ impl<'nvml> Device<'nvml> {
         error_type: MemoryError,
         counter_type: EccCounter,
     ) -> Result<EccErrorCounts, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetDetailedEccErrors.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDetailedEccErrors.as_ref(),
+            "nvmlDeviceGetDetailedEccErrors",
+        )?;
 
         unsafe {
             let mut counts: nvmlEccErrorCounts_t = mem::zeroed();
@@ -1439,8 +2046,11 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetDisplayActive")]
     pub fn is_display_active(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetDisplayActive.as_ref())?;
-
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDisplayActive.as_ref(),
+            "nvmlDeviceGetDisplayActive",
+        )?;
+
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
             nvml_try(sym(self.device, &mut state))?;
@@ -1468,7 +2078,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetDisplayMode")]
     pub fn is_display_connected(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetDisplayMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDisplayMode.as_ref(),
+            "nvmlDeviceGetDisplayMode",
+        )?;
 
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
@@ -1507,7 +2120,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "windows")]
     #[doc(alias = "nvmlDeviceGetDriverModel")]
     pub fn driver_model(&self) -> Result<DriverModelState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetDriverModel.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDriverModel.as_ref(),
+            "nvmlDeviceGetDriverModel",
+        )?;
 
         unsafe {
             let mut current: nvmlDriverModel_t = mem::zeroed();
@@ -1522,6 +2138,40 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets whether this `Device`'s driver model is settled or needs a reboot to
+    apply a pending change made with `.set_driver_model()`.
+
+    This compares the current and pending modes from `.driver_model()`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if the platform is not Windows
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Fermi and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Windows.
+    */
+    #[cfg(target_os = "windows")]
+    pub fn driver_model_transition(&self) -> Result<DriverModelTransition, NvmlError> {
+        let DriverModelState { current, pending } = self.driver_model()?;
+
+        Ok(if current == pending {
+            DriverModelTransition::Stable(current)
+        } else {
+            DriverModelTransition::RebootRequired { current, pending }
+        })
+    }
+
     /**
     Get the current and pending ECC modes for this `Device`.
 
@@ -1546,7 +2196,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetEccMode")]
     pub fn is_ecc_enabled(&self) -> Result<EccModeState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEccMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEccMode.as_ref(),
+            "nvmlDeviceGetEccMode",
+        )?;
 
         unsafe {
             let mut current: nvmlEnableState_t = mem::zeroed();
@@ -1580,7 +2233,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetEncoderUtilization")]
     pub fn encoder_utilization(&self) -> Result<UtilizationInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEncoderUtilization.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEncoderUtilization.as_ref(),
+            "nvmlDeviceGetEncoderUtilization",
+        )?;
 
         unsafe {
             let mut utilization: c_uint = mem::zeroed();
@@ -1613,7 +2269,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetEncoderCapacity")]
     pub fn encoder_capacity(&self, for_type: EncoderType) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEncoderCapacity.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEncoderCapacity.as_ref(),
+            "nvmlDeviceGetEncoderCapacity",
+        )?;
 
         unsafe {
             let mut capacity: c_uint = mem::zeroed();
@@ -1641,7 +2300,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetEncoderStats")]
     pub fn encoder_stats(&self) -> Result<EncoderStats, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEncoderStats.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEncoderStats.as_ref(),
+            "nvmlDeviceGetEncoderStats",
+        )?;
 
         unsafe {
             let mut session_count: c_uint = mem::zeroed();
@@ -1682,7 +2344,10 @@ impl<'nvml> Device<'nvml> {
     // TODO: Test this with an active session and make sure it works
     #[doc(alias = "nvmlDeviceGetEncoderSessions")]
     pub fn encoder_sessions(&self) -> Result<Vec<EncoderSessionInfo>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEncoderSessions.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEncoderSessions.as_ref(),
+            "nvmlDeviceGetEncoderSessions",
+        )?;
 
         unsafe {
             let mut count = match self.encoder_sessions_count()? {
@@ -1713,7 +2378,10 @@ impl<'nvml> Device<'nvml> {
     */
     // tested as part of the above
     fn encoder_sessions_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEncoderSessions.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEncoderSessions.as_ref(),
+            "nvmlDeviceGetEncoderSessions",
+        )?;
 
         unsafe {
             let mut count: c_uint = 0;
@@ -1747,7 +2415,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetEnforcedPowerLimit")]
     pub fn enforced_power_limit(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetEnforcedPowerLimit.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetEnforcedPowerLimit.as_ref(),
+            "nvmlDeviceGetEnforcedPowerLimit",
+        )?;
 
         unsafe {
             let mut limit: c_uint = mem::zeroed();
@@ -1777,7 +2448,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceGetGpcClkVfOffset")]
     pub fn gpc_clock_vf_offset(&self) -> Result<i32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetGpcClkVfOffset.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpcClkVfOffset.as_ref(),
+            "nvmlDeviceGetGpcClkVfOffset",
+        )?;
 
         unsafe {
             let mut offset: c_int = mem::zeroed();
@@ -1807,7 +2481,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceGetGpcClkVfOffset")]
     pub fn set_gpc_clock_vf_offset(&self, offset: i32) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetGpcClkVfOffset.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetGpcClkVfOffset.as_ref(),
+            "nvmlDeviceSetGpcClkVfOffset",
+        )?;
 
         unsafe { nvml_try(sym(self.device, offset)) }
     }
@@ -1832,7 +2509,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceGetGpcMemClkVfOffset")]
     pub fn mem_clock_vf_offset(&self) -> Result<i32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMemClkVfOffset.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMemClkVfOffset.as_ref(),
+            "nvmlDeviceGetMemClkVfOffset",
+        )?;
 
         unsafe {
             let mut offset: c_int = mem::zeroed();
@@ -1862,7 +2542,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetGpcMemClkVfOffset")]
     pub fn set_mem_clock_vf_offset(&self, offset: i32) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetMemClkVfOffset.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetMemClkVfOffset.as_ref(),
+            "nvmlDeviceSetMemClkVfOffset",
+        )?;
 
         unsafe { nvml_try(sym(self.device, offset)) }
     }
@@ -1892,7 +2575,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetFanSpeed_v2")]
     pub fn fan_speed(&self, fan_idx: u32) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFanSpeed_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFanSpeed_v2.as_ref(),
+            "nvmlDeviceGetFanSpeed_v2",
+        )?;
 
         unsafe {
             let mut speed: c_uint = mem::zeroed();
@@ -1930,7 +2616,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetFanSpeedRPM")]
     pub fn fan_speed_rpm(&self, fan_idx: u32) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFanSpeedRPM.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFanSpeedRPM.as_ref(),
+            "nvmlDeviceGetFanSpeedRPM",
+        )?;
 
         unsafe {
             let mut fan_speed: nvmlFanSpeedInfo_t = mem::zeroed();
@@ -1964,7 +2653,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetMinMaxFanSpeed")]
     pub fn min_max_fan_speed(&self) -> Result<(u32, u32), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMinMaxFanSpeed.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMinMaxFanSpeed.as_ref(),
+            "nvmlDeviceGetMinMaxFanSpeed",
+        )?;
 
         unsafe {
             let mut min = mem::zeroed();
@@ -1994,7 +2686,10 @@ impl<'nvml> Device<'nvml> {
      */
     #[doc(alias = "nvmlGetFanControlPolicy_v2")]
     pub fn fan_control_policy(&self, fan_idx: u32) -> Result<FanControlPolicy, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFanControlPolicy_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFanControlPolicy_v2.as_ref(),
+            "nvmlDeviceGetFanControlPolicy_v2",
+        )?;
 
         unsafe {
             let mut policy: nvmlFanControlPolicy_t = mem::zeroed();
@@ -2028,7 +2723,10 @@ impl<'nvml> Device<'nvml> {
         fan_idx: u32,
         policy: FanControlPolicy,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetFanControlPolicy.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetFanControlPolicy.as_ref(),
+            "nvmlDeviceSetFanControlPolicy",
+        )?;
 
         unsafe { nvml_try(sym(self.device, fan_idx, policy.as_c())) }
     }
@@ -2056,7 +2754,10 @@ impl<'nvml> Device<'nvml> {
      */
     #[doc(alias = "nvmlDeviceSetFanSpeed_v2")]
     pub fn set_fan_speed(&mut self, fan_idx: u32, speed: u32) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetFanSpeed_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetFanSpeed_v2.as_ref(),
+            "nvmlDeviceSetFanSpeed_v2",
+        )?;
 
         unsafe { nvml_try(sym(self.device, fan_idx, speed)) }
     }
@@ -2081,7 +2782,10 @@ impl<'nvml> Device<'nvml> {
      */
     #[doc(alias = "nvmlDeviceSetDefaultFanSpeed_v2")]
     pub fn set_default_fan_speed(&mut self, fan_idx: u32) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetDefaultFanSpeed_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetDefaultFanSpeed_v2.as_ref(),
+            "nvmlDeviceSetDefaultFanSpeed_v2",
+        )?;
 
         unsafe { nvml_try(sym(self.device, fan_idx)) }
     }
@@ -2102,7 +2806,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetNumFans")]
     pub fn num_fans(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetNumFans.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetNumFans.as_ref(),
+            "nvmlDeviceGetNumFans",
+        )?;
 
         unsafe {
             let mut count: c_uint = mem::zeroed();
@@ -2135,7 +2842,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetGpuOperationMode")]
     pub fn gpu_operation_mode(&self) -> Result<OperationModeState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetGpuOperationMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpuOperationMode.as_ref(),
+            "nvmlDeviceGetGpuOperationMode",
+        )?;
 
         unsafe {
             let mut current: nvmlGpuOperationMode_t = mem::zeroed();
@@ -2170,6 +2880,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetGraphicsRunningProcesses_v3
                 .as_ref(),
+            "nvmlDeviceGetGraphicsRunningProcesses_v3",
         )?;
 
         unsafe {
@@ -2211,6 +2922,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetGraphicsRunningProcesses_v3
                 .as_ref(),
+            "nvmlDeviceGetGraphicsRunningProcesses_v3",
         )?;
 
         unsafe {
@@ -2243,6 +2955,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetGraphicsRunningProcesses_v2
                 .as_ref(),
+            "nvmlDeviceGetGraphicsRunningProcesses_v2",
         )?;
 
         unsafe {
@@ -2284,6 +2997,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetGraphicsRunningProcesses_v2
                 .as_ref(),
+            "nvmlDeviceGetGraphicsRunningProcesses_v2",
         )?;
 
         unsafe {
@@ -2296,6 +3010,42 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the amount of GPU memory used by each process running on this
+    `Device`, keyed by PID.
+
+    This combines `.running_compute_processes()` and
+    `.running_graphics_processes()`. A process that appears more than once
+    (for example, one with both a compute and a graphics context) is reported
+    with the largest amount of memory NVML returned for it.
+    `UsedGpuMemory::Unavailable` is reported as `0`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    pub fn process_memory_map(&self) -> Result<BTreeMap<u32, u64>, NvmlError> {
+        let mut map = BTreeMap::new();
+
+        let compute = self.running_compute_processes()?;
+        let graphics = self.running_graphics_processes()?;
+
+        for process in compute.into_iter().chain(graphics) {
+            let used = match process.used_gpu_memory {
+                UsedGpuMemory::Used(bytes) => bytes,
+                UsedGpuMemory::Unavailable => 0,
+            };
+
+            let entry = map.entry(process.pid).or_insert(0);
+            *entry = (*entry).max(used);
+        }
+
+        Ok(map)
+    }
+
     /**
     Gets utilization stats for relevant currently running processes.
 
@@ -2325,7 +3075,10 @@ impl<'nvml> Device<'nvml> {
     where
         T: Into<Option<u64>>,
     {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetProcessUtilization.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetProcessUtilization.as_ref(),
+            "nvmlDeviceGetProcessUtilization",
+        )?;
 
         unsafe {
             let last_seen_timestamp = last_seen_timestamp.into().unwrap_or(0);
@@ -2355,7 +3108,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         last_seen_timestamp: u64,
     ) -> Result<c_uint, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetProcessUtilization.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetProcessUtilization.as_ref(),
+            "nvmlDeviceGetProcessUtilization",
+        )?;
 
         unsafe {
             let mut count: c_uint = 0;
@@ -2387,7 +3143,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetIndex")]
     pub fn index(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetIndex.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetIndex.as_ref(),
+            "nvmlDeviceGetIndex",
+        )?;
 
         unsafe {
             let mut index: c_uint = mem::zeroed();
@@ -2426,6 +3185,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetInforomConfigurationChecksum
                 .as_ref(),
+            "nvmlDeviceGetInforomConfigurationChecksum",
         )?;
 
         unsafe {
@@ -2461,7 +3221,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetInforomImageVersion")]
     pub fn info_rom_image_version(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetInforomImageVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetInforomImageVersion.as_ref(),
+            "nvmlDeviceGetInforomImageVersion",
+        )?;
 
         unsafe {
             let mut version_vec = vec![0; NVML_DEVICE_INFOROM_VERSION_BUFFER_SIZE as usize];
@@ -2502,7 +3265,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetInforomVersion")]
     pub fn info_rom_version(&self, object: InfoRom) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetInforomVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetInforomVersion.as_ref(),
+            "nvmlDeviceGetInforomVersion",
+        )?;
 
         unsafe {
             let mut version_vec = vec![0; NVML_DEVICE_INFOROM_VERSION_BUFFER_SIZE as usize];
@@ -2541,7 +3307,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetMaxClockInfo")]
     pub fn max_clock_info(&self, clock_type: Clock) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMaxClockInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMaxClockInfo.as_ref(),
+            "nvmlDeviceGetMaxClockInfo",
+        )?;
 
         unsafe {
             let mut clock: c_uint = mem::zeroed();
@@ -2556,7 +3325,9 @@ impl<'nvml> Device<'nvml> {
     Gets the max PCIe link generation possible with this `Device` and system.
 
     For a gen 2 PCIe device attached to a gen 1 PCIe bus, the max link generation
-    this function will report is generation 1.
+    this function will report is generation 1. Compare with
+    `.max_pcie_link_gen_device()`, which ignores the system, to tell whether
+    this `Device` or its slot is limiting the link.
 
     # Errors
 
@@ -2574,7 +3345,44 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetMaxPcieLinkGeneration")]
     pub fn max_pcie_link_gen(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMaxPcieLinkGeneration.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMaxPcieLinkGeneration.as_ref(),
+            "nvmlDeviceGetMaxPcieLinkGeneration",
+        )?;
+
+        unsafe {
+            let mut max_gen: c_uint = mem::zeroed();
+
+            nvml_try(sym(self.device, &mut max_gen))?;
+
+            Ok(max_gen)
+        }
+    }
+
+    /**
+    Gets the max PCIe link generation supported by this `Device` itself,
+    regardless of what the system it is attached to supports.
+
+    See `.max_pcie_link_gen()` for the generation negotiated with the system.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if PCIe link information is not available
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Fermi and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetGpuMaxPcieLinkGeneration")]
+    pub fn max_pcie_link_gen_device(&self) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpuMaxPcieLinkGeneration.as_ref(),
+            "nvmlDeviceGetGpuMaxPcieLinkGeneration",
+        )?;
 
         unsafe {
             let mut max_gen: c_uint = mem::zeroed();
@@ -2607,7 +3415,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetMaxPcieLinkWidth")]
     pub fn max_pcie_link_width(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMaxPcieLinkWidth.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMaxPcieLinkWidth.as_ref(),
+            "nvmlDeviceGetMaxPcieLinkWidth",
+        )?;
 
         unsafe {
             let mut max_width: c_uint = mem::zeroed();
@@ -2646,7 +3457,10 @@ impl<'nvml> Device<'nvml> {
         counter_type: EccCounter,
         location: MemoryLocation,
     ) -> Result<u64, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMemoryErrorCounter.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMemoryErrorCounter.as_ref(),
+            "nvmlDeviceGetMemoryErrorCounter",
+        )?;
 
         unsafe {
             let mut count: c_ulonglong = mem::zeroed();
@@ -2676,6 +3490,10 @@ impl<'nvml> Device<'nvml> {
     of used memory is equal to the sum of memory allocated by all active channels on
     this `Device`.
 
+    This uses the v2 API: memory set aside by the driver is reported in `reserved`
+    and is not included in `used`, so `used` is lower than what the v1 API (and
+    older versions of this wrapper) reported for the same workload.
+
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
@@ -2686,8 +3504,12 @@ impl<'nvml> Device<'nvml> {
     // Checked against local
     // Tested
     #[doc(alias = "nvmlDeviceGetMemoryInfo")]
+    #[doc(alias = "nvmlDeviceGetMemoryInfo_v2")]
     pub fn memory_info(&self) -> Result<MemoryInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMemoryInfo_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMemoryInfo_v2.as_ref(),
+            "nvmlDeviceGetMemoryInfo_v2",
+        )?;
 
         unsafe {
             let mut info: nvmlMemory_v2_t = mem::zeroed();
@@ -2700,6 +3522,42 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the amount of memory the driver has set aside on this `Device`, in
+    bytes.
+
+    This is the `reserved` field of `.memory_info()`; it is not counted in
+    `used`.
+
+    # Errors
+
+    Same as `.memory_info()`.
+    */
+    pub fn reserved_memory(&self) -> Result<u64, NvmlError> {
+        Ok(self.memory_info()?.reserved)
+    }
+
+    /**
+    Gets this `Device`'s framebuffer and BAR1 memory usage in one call.
+
+    Regions that this `Device` cannot report are `None` rather than failing the
+    whole call. Memory reserved by the driver is included in the framebuffer
+    region's `reserved` field.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    pub fn memory_report(&self) -> Result<MemoryReport, NvmlError> {
+        Ok(MemoryReport {
+            framebuffer: ok_if_supported(self.memory_info())?,
+            bar1: ok_if_supported(self.bar1_memory_info())?,
+        })
+    }
+
     /**
     Gets the minor number for this `Device`.
 
@@ -2723,7 +3581,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetMinorNumber")]
     pub fn minor_number(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMinorNumber.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMinorNumber.as_ref(),
+            "nvmlDeviceGetMinorNumber",
+        )?;
 
         unsafe {
             let mut number: c_uint = mem::zeroed();
@@ -2752,7 +3613,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetMultiGpuBoard")]
     pub fn is_multi_gpu_board(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMultiGpuBoard.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMultiGpuBoard.as_ref(),
+            "nvmlDeviceGetMultiGpuBoard",
+        )?;
 
         unsafe {
             let mut int_bool: c_uint = mem::zeroed();
@@ -2786,7 +3650,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetGpuInstanceProfileInfo")]
     pub fn profile_info(&self, profile: u32) -> Result<ProfileInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetGpuInstanceProfileInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpuInstanceProfileInfo.as_ref(),
+            "nvmlDeviceGetGpuInstanceProfileInfo",
+        )?;
 
         unsafe {
             let mut info: nvmlGpuInstanceProfileInfo_t = mem::zeroed();
@@ -2826,6 +3693,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetGpuInstancePossiblePlacements_v2
                 .as_ref(),
+            "nvmlDeviceGetGpuInstancePossiblePlacements_v2",
         )?;
 
         unsafe {
@@ -2849,85 +3717,360 @@ impl<'nvml> Device<'nvml> {
     }
 
     /**
-    Checks if the `Device`supports multi partitioned GPU feature and if enabled.
-    Not to confuse with `is_multi_gpu_board`, MIG is a single GPU
-    being able to be split into isolated instances, a sort of "NUMA" for GPU.
-    If the `Device` supports MIG, we can have its current mode (enabled/disabled)
-    and, if set, its pending mode for the next system reboot.
+    Gets how many more MIG GPU instances with the given GPU instance profile
+    can currently be created on this `Device`.
+
+    `profile_id` is the `id` of a `ProfileInfo` obtained from `.profile_info()`.
+
+    Check this before `.create_gpu_instance()` to avoid failing for lack of
+    resources.
+
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
-    * `InvalidArg`, if this `Device` is invalid
-    * `NotSupported`, if this `Device` does not support this feature
-    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `InvalidArg`, if this `Device` or `profile_id` is invalid
+    * `NotSupported`, if MIG is not enabled or `profile_id` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
     * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
     */
-    #[doc(alias = "nvmlDeviceGetMigMode")]
-    pub fn mig_mode(&self) -> Result<MigMode, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMigMode.as_ref())?;
+    #[cfg(target_os = "linux")]
+    #[doc(alias = "nvmlDeviceGetGpuInstanceRemainingCapacity")]
+    pub fn gpu_instance_remaining_capacity(&self, profile_id: u32) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlDeviceGetGpuInstanceRemainingCapacity
+                .as_ref(),
+            "nvmlDeviceGetGpuInstanceRemainingCapacity",
+        )?;
 
         unsafe {
-            let mut mode: MigMode = mem::zeroed();
-            nvml_try(sym(self.device, &mut mode.current, &mut mode.pending))?;
+            let mut count: c_uint = 0;
+            nvml_try(sym(self.device, profile_id, &mut count))?;
 
-            Ok(mode)
+            Ok(count)
         }
     }
 
     /**
-    Set the Device MIG mode ; even if the GPU supports this feature,
-    the setting can still fail (e.g. device still in use).
+    Creates a MIG GPU instance with the given GPU instance profile on this
+    `Device`.
+
+    `profile_id` is the `id` of a `ProfileInfo` obtained from `.profile_info()`.
+    MIG mode must be enabled.
+
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
-    * `InvalidArg`, if this `Device` is invalid
-    * `NotSupported`, if this `Device` does not support this feature
-    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `InvalidArg`, if this `Device` or `profile_id` is invalid
+    * `NotSupported`, if MIG is not enabled or `profile_id` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `UnexpectedVariant`, if the requested GPU instance could not be created
+      because there aren't enough resources left on this `Device`
     * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
     */
-    #[doc(alias = "nvmlDeviceSetMigMode")]
-    pub fn set_mig_mode(&self, m: bool) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetMigMode.as_ref())?;
+    #[cfg(target_os = "linux")]
+    #[doc(alias = "nvmlDeviceCreateGpuInstance")]
+    pub fn create_gpu_instance(&self, profile_id: u32) -> Result<GpuInstance<'_>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceCreateGpuInstance.as_ref(),
+            "nvmlDeviceCreateGpuInstance",
+        )?;
 
         unsafe {
-            let mode: c_uint = match m {
-                true => NVML_DEVICE_MIG_ENABLE,
-                false => NVML_DEVICE_MIG_DISABLE,
-            };
-            let mut status: c_uint = 0;
+            let mut handle: nvmlGpuInstance_t = mem::zeroed();
+            nvml_try(sym(self.device, profile_id, &mut handle))?;
 
-            nvml_try(sym(self.device, mode, &mut status))?;
-            Ok(status)
+            Ok(GpuInstance::new(self, handle))
         }
     }
 
     /**
-     Gets the MIG device handle from `index` on a parent physical GPU
-     # Errors
+    Gets the MIG GPU instance with the given ID on this `Device`.
+
+    Use this to reattach to a GPU instance whose ID was obtained from
+    `GpuInstance.info()` earlier, e.g. before a process restart.
+
+    # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
-    * `InvalidArg`, if this `Device` is invalid
-    * `NotSupported`, if this `Device` does not support this feature
-    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `InvalidArg`, if this `Device` or `id` is invalid
+    * `NotSupported`, if MIG is not enabled
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `NotFound`, if no GPU instance with the given `id` exists
     * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
     */
-    #[doc(alias = "nvmlDeviceGetMigDeviceHandleByIndex")]
-    pub fn mig_device_by_index(&self, index: u32) -> Result<Device<'nvml>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMigDeviceHandleByIndex.as_ref())?;
+    #[cfg(target_os = "linux")]
+    #[doc(alias = "nvmlDeviceGetGpuInstanceById")]
+    pub fn gpu_instance_by_id(&self, id: u32) -> Result<GpuInstance<'_>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpuInstanceById.as_ref(),
+            "nvmlDeviceGetGpuInstanceById",
+        )?;
 
         unsafe {
-            let mut parent: nvmlDevice_t = mem::zeroed();
-            nvml_try(sym(self.device, index, &mut parent))?;
+            let mut handle: nvmlGpuInstance_t = mem::zeroed();
+            nvml_try(sym(self.device, id, &mut handle))?;
 
-            Ok(Device::new(parent, self.nvml))
+            Ok(GpuInstance::new(self, handle))
         }
     }
 
     /**
-     Gets the parent device from the MiG device handle
-     # Errors
+    Gets the existing MIG GPU instances with the given GPU instance profile on
+    this `Device`.
 
-    * `Uninitialized`, if the library has not been successfully initialized
+    `profile` is a `ProfileInfo` obtained from `.profile_info()`. Its `id` is
+    passed to NVML and its `instance_count` sizes the buffer.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` or `profile` is invalid
+    * `NotSupported`, if MIG is not enabled or `profile` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+    */
+    #[cfg(target_os = "linux")]
+    #[doc(alias = "nvmlDeviceGetGpuInstances")]
+    pub fn gpu_instances(&self, profile: &ProfileInfo) -> Result<Vec<GpuInstance<'_>>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpuInstances.as_ref(),
+            "nvmlDeviceGetGpuInstances",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = 0;
+            // The buffer must be able to hold every instance the profile supports
+            let mut handles: Vec<nvmlGpuInstance_t> =
+                vec![mem::zeroed(); profile.instance_count as usize];
+
+            nvml_try(sym(
+                self.device,
+                profile.id,
+                handles.as_mut_ptr(),
+                &mut count,
+            ))?;
+
+            handles.truncate(count as usize);
+            Ok(handles
+                .into_iter()
+                .map(|handle| GpuInstance::new(self, handle))
+                .collect())
+        }
+    }
+
+    /**
+    Gets how many more compute instances with the given compute instance
+    profile can currently be created across the existing GPU instances with
+    the given GPU instance profile on this `Device`.
+
+    All three arguments are indices rather than IDs: `gi_profile` is one of
+    the `NVML_GPU_INSTANCE_PROFILE_*` constants, as accepted by
+    `.profile_info()`, and `ci_profile` and `ci_engine_profile` are one of
+    the `NVML_COMPUTE_INSTANCE_PROFILE_*` and
+    `NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_*` constants respectively, as
+    accepted by `GpuInstance.compute_instance_profile_info()`. The IDs are
+    resolved internally.
+
+    This sums `GpuInstance.compute_instance_remaining_capacity()` over
+    `.gpu_instances()`. Returns `None` if no GPU instance with `gi_profile`
+    exists yet, so that case can be told apart from existing GPU instances
+    being full; check `.gpu_instance_remaining_capacity()` to see whether one
+    can be created.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` or any of the profile indices is invalid
+    * `NotSupported`, if MIG is not enabled or any of the profiles isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+    */
+    #[cfg(target_os = "linux")]
+    pub fn compute_instance_remaining_by_profile(
+        &self,
+        gi_profile: u32,
+        ci_profile: u32,
+        ci_engine_profile: u32,
+    ) -> Result<Option<u32>, NvmlError> {
+        let gi_profile = self.profile_info(gi_profile)?;
+        let instances = self.gpu_instances(&gi_profile)?;
+
+        if instances.is_empty() {
+            return Ok(None);
+        }
+
+        instances
+            .iter()
+            .map(|instance| {
+                let ci_profile =
+                    instance.compute_instance_profile_info(ci_profile, ci_engine_profile)?;
+
+                instance.compute_instance_remaining_capacity(ci_profile.id)
+            })
+            .sum::<Result<u32, NvmlError>>()
+            .map(Some)
+    }
+
+    /**
+    Checks if the `Device`supports multi partitioned GPU feature and if enabled.
+    Not to confuse with `is_multi_gpu_board`, MIG is a single GPU
+    being able to be split into isolated instances, a sort of "NUMA" for GPU.
+    If the `Device` supports MIG, we can have its current mode (enabled/disabled)
+    and, if set, its pending mode for the next system reboot.
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetMigMode")]
+    pub fn mig_mode(&self) -> Result<MigMode, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMigMode.as_ref(),
+            "nvmlDeviceGetMigMode",
+        )?;
+
+        unsafe {
+            let mut mode: MigMode = mem::zeroed();
+            nvml_try(sym(self.device, &mut mode.current, &mut mode.pending))?;
+
+            Ok(mode)
+        }
+    }
+
+    /**
+    Checks whether MIG mode is currently enabled on this `Device`.
+
+    Only the current mode is considered; a pending mode change that takes
+    effect after the next reset is ignored. Devices that don't support MIG
+    return `false` rather than `NotSupported`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    pub fn is_mig_enabled(&self) -> Result<bool, NvmlError> {
+        match self.mig_mode() {
+            Ok(mode) => Ok(mode.current == NVML_DEVICE_MIG_ENABLE),
+            Err(NvmlError::NotSupported) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /**
+    Set the Device MIG mode ; even if the GPU supports this feature,
+    the setting can still fail (e.g. device still in use).
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceSetMigMode")]
+    pub fn set_mig_mode(&self, m: bool) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetMigMode.as_ref(),
+            "nvmlDeviceSetMigMode",
+        )?;
+
+        unsafe {
+            let mode: c_uint = match m {
+                true => NVML_DEVICE_MIG_ENABLE,
+                false => NVML_DEVICE_MIG_DISABLE,
+            };
+            let mut status: c_uint = 0;
+
+            nvml_try(sym(self.device, mode, &mut status))?;
+            Ok(status)
+        }
+    }
+
+    /**
+     Gets the MIG device handle from `index` on a parent physical GPU
+
+     The returned `Device` can be used like any other. NVML accepts MIG device
+     handles for queries such as `.memory_info()`, `.bar1_memory_info()`,
+     `.utilization_rates()`, `.running_compute_processes()` and `.uuid()`, which
+     report on the MIG device's slice rather than on the parent GPU. Many other
+     queries will return `NotSupported` or `InvalidArg` for a MIG device.
+
+     # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetMigDeviceHandleByIndex")]
+    pub fn mig_device_by_index(&self, index: u32) -> Result<Device<'nvml>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMigDeviceHandleByIndex.as_ref(),
+            "nvmlDeviceGetMigDeviceHandleByIndex",
+        )?;
+
+        unsafe {
+            let mut parent: nvmlDevice_t = mem::zeroed();
+            nvml_try(sym(self.device, index, &mut parent))?;
+
+            Ok(Device::new(parent, self.nvml))
+        }
+    }
+
+    /**
+     Gets the parent device from the MiG device handle
+     # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
     * `InvalidArg`, if this `Device` is invalid
     * `NotSupported`, if this `Device` does not support this feature
     * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
@@ -2940,6 +4083,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetDeviceHandleFromMigDeviceHandle
                 .as_ref(),
+            "nvmlDeviceGetDeviceHandleFromMigDeviceHandle",
         )?;
 
         unsafe {
@@ -2962,7 +4106,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetMaxMigDeviceCount")]
     pub fn mig_device_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMaxMigDeviceCount.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMaxMigDeviceCount.as_ref(),
+            "nvmlDeviceGetMaxMigDeviceCount",
+        )?;
 
         unsafe {
             let mut count: c_uint = 0;
@@ -2983,7 +4130,10 @@ impl<'nvml> Device<'nvml> {
     * `Unknown`, on any unexpected error
     */
     pub fn mig_is_mig_device_handle(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceIsMigDeviceHandle.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceIsMigDeviceHandle.as_ref(),
+            "nvmlDeviceIsMigDeviceHandle",
+        )?;
 
         unsafe {
             let mut mig_handle: c_uint = 0;
@@ -3010,19 +4160,35 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetName")]
     pub fn name(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetName.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetName.as_ref(),
+            "nvmlDeviceGetName",
+        )?;
 
         unsafe {
-            let mut name_vec = vec![0; NVML_DEVICE_NAME_V2_BUFFER_SIZE as usize];
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_NAME_V2_BUFFER_SIZE)?;
+            Ok(raw.to_str()?.into())
+        }
+    }
 
-            nvml_try(sym(
-                self.device,
-                name_vec.as_mut_ptr(),
-                NVML_DEVICE_NAME_V2_BUFFER_SIZE,
-            ))?;
+    /**
+    Like `.name()`, but replaces any invalid UTF-8 in the string obtained from
+    the C function with `U+FFFD REPLACEMENT CHARACTER` instead of erroring.
+
+    # Errors
+
+    The same as `.name()`, except that `Utf8Error` is never returned.
+    */
+    #[doc(alias = "nvmlDeviceGetName")]
+    pub fn name_lossy(&self) -> Result<String, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetName.as_ref(),
+            "nvmlDeviceGetName",
+        )?;
 
-            let name_raw = CStr::from_ptr(name_vec.as_ptr());
-            Ok(name_raw.to_str()?.into())
+        unsafe {
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_NAME_V2_BUFFER_SIZE)?;
+            Ok(raw.to_string_lossy().into_owned())
         }
     }
 
@@ -3043,7 +4209,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetPciInfo_v3")]
     pub fn pci_info(&self) -> Result<PciInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPciInfo_v3.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPciInfo_v3.as_ref(),
+            "nvmlDeviceGetPciInfo_v3",
+        )?;
 
         unsafe {
             let mut pci_info: nvmlPciInfo_t = mem::zeroed();
@@ -3053,6 +4222,55 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the PCI bus ID of this `Device` in the given format.
+
+    Use this to match bus IDs printed by different `nvidia-smi` versions
+    without string manipulation. See `PciInfo.formatted_bus_id()`.
+
+    # Errors
+
+    Same as `.pci_info()`.
+    */
+    pub fn pci_bus_id(&self, format: BusIdFormat) -> Result<String, NvmlError> {
+        Ok(self.pci_info()?.formatted_bus_id(format))
+    }
+
+    /**
+    Gets the extended PCI attributes of this `Device`.
+
+    In addition to what `.pci_info()` provides, this includes the PCI base and
+    sub class codes.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if the GPU has fallen off the bus or is otherwise inaccessible
+    * `Utf8Error`, if a string obtained from the C function is not valid Utf8
+    * `Unknown`, on any unexpected error
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetPciInfoExt")]
+    pub fn pci_info_ext(&self) -> Result<PciInfoExt, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPciInfoExt.as_ref(),
+            "nvmlDeviceGetPciInfoExt",
+        )?;
+
+        unsafe {
+            let mut pci_info: nvmlPciInfoExt_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(PciInfoExt, 1), as detailed in nvml.h
+            pci_info.version =
+                (std::mem::size_of::<nvmlPciInfoExt_v1_t>() | (1_usize << 24_usize)) as u32;
+
+            nvml_try(sym(self.device, &mut pci_info))?;
+
+            PciInfoExt::try_from(pci_info)
+        }
+    }
+
     /**
     Gets the PCIe replay counter.
 
@@ -3072,7 +4290,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetPcieReplayCounter")]
     pub fn pcie_replay_counter(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPcieReplayCounter.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPcieReplayCounter.as_ref(),
+            "nvmlDeviceGetPcieReplayCounter",
+        )?;
 
         unsafe {
             let mut value: c_uint = mem::zeroed();
@@ -3108,7 +4329,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetPcieThroughput")]
     pub fn pcie_throughput(&self, counter: PcieUtilCounter) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPcieThroughput.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPcieThroughput.as_ref(),
+            "nvmlDeviceGetPcieThroughput",
+        )?;
 
         unsafe {
             let mut throughput: c_uint = mem::zeroed();
@@ -3119,6 +4343,58 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets PCIe throughput as a percentage of the bandwidth of the currently
+    negotiated link.
+
+    Throughput comes from `.pcie_throughput()` and the link bandwidth is
+    derived from `.pcie_link_speed()` (the per-lane transfer rate) and
+    `.current_pcie_link_width()`, accounting for the line encoding overhead of
+    the link's PCIe generation.
+
+    Note that NVML samples the throughput counters over a 20ms interval, so
+    individual readings can be noisy; poll and average them if you need a
+    smoother value. Readings taken while the link is downtrained to save power
+    may also briefly exceed 100%.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell and newer fully supported devices.
+
+    # Environment Support
+
+    This method is not supported on virtual machines running vGPUs.
+    */
+    pub fn pcie_utilization(&self) -> Result<PcieUtilization, NvmlError> {
+        let tx = self.pcie_throughput(PcieUtilCounter::Send)?;
+        let rx = self.pcie_throughput(PcieUtilCounter::Receive)?;
+
+        let speed = self.pcie_link_speed()?;
+        let width = self.current_pcie_link_width()?;
+
+        // Gen 1 and 2 use 8b/10b encoding; gen 3 and newer use 128b/130b
+        let efficiency = if speed <= 5000 { 0.8 } else { 128.0 / 130.0 };
+        // MT/s per lane -> KB/s for the whole link
+        let bandwidth = speed as f64 * width as f64 * efficiency / 8.0 * 1000.0;
+
+        if bandwidth == 0.0 {
+            return Err(NvmlError::NotSupported);
+        }
+
+        Ok(PcieUtilization {
+            tx_percent: (tx as f64 / bandwidth * 100.0) as f32,
+            rx_percent: (rx as f64 / bandwidth * 100.0) as f32,
+        })
+    }
+
     /**
     Gets the current performance state for this `Device`. 0 == max, 15 == min.
 
@@ -3139,7 +4415,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetPerformanceState")]
     pub fn performance_state(&self) -> Result<PerformanceState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPerformanceState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPerformanceState.as_ref(),
+            "nvmlDeviceGetPerformanceState",
+        )?;
 
         unsafe {
             let mut state: nvmlPstates_t = mem::zeroed();
@@ -3150,30 +4429,106 @@ impl<'nvml> Device<'nvml> {
     }
 
     /**
-    Gets whether or not persistent mode is enabled for this `Device`.
+    Gets the current performance state along with the graphics clock, memory
+    clock and throttle reasons observed in that state.
 
-    When driver persistence mode is enabled the driver software is not torn down
-    when the last client disconnects. This feature is disabled by default.
+    NVML has no single call for these, so they are read one after another. The
+    performance state is read again afterwards and the whole read is retried
+    if it changed, so the returned clocks and throttle reasons belong to the
+    returned performance state unless it keeps changing across every attempt.
 
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
     * `InvalidArg`, if this `Device` is invalid
-    * `NotSupported`, if this `Device` does not support this feature
+    * `NotSupported`, if this `Device` does not support one of the queries
     * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
     * `UnexpectedVariant`, for which you can read the docs for
     * `Unknown`, on any unexpected error
 
-    # Platform Support
+    # Device Support
 
-    Only supports Linux.
+    Supports all _fully supported_ devices.
     */
-    // Checked against local
-    // Tested
-    #[cfg(target_os = "linux")]
+    pub fn performance_snapshot(&self) -> Result<PerformanceSnapshot, NvmlError> {
+        const ATTEMPTS: usize = 3;
+
+        let mut pstate = self.performance_state()?;
+        let mut attempt = 1;
+
+        loop {
+            let graphics_clock = self.clock_info(Clock::Graphics)?;
+            let memory_clock = self.clock_info(Clock::Memory)?;
+            let throttle_reasons = self.current_throttle_reasons()?;
+            let pstate_after = self.performance_state()?;
+
+            if pstate_after == pstate || attempt == ATTEMPTS {
+                return Ok(PerformanceSnapshot {
+                    pstate: pstate_after,
+                    graphics_clock,
+                    memory_clock,
+                    throttle_reasons,
+                });
+            }
+
+            pstate = pstate_after;
+            attempt += 1;
+        }
+    }
+
+    /**
+    Gets the temperature, first fan speed, power usage, throttle reasons and
+    utilization of this `Device` in one call.
+
+    Each reading takes a single NVML call. Readings that this `Device` cannot
+    report are `None` rather than failing the whole call, which makes this
+    suitable for polling devices of mixed capabilities.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    pub fn health_snapshot(&self) -> Result<HealthSnapshot, NvmlError> {
+        Ok(HealthSnapshot {
+            temperature: ok_if_supported(self.temperature(TemperatureSensor::Gpu))?,
+            fan_speed_percent: ok_if_supported(self.fan_speed(0))?,
+            power_usage_mw: ok_if_supported(self.power_usage())?,
+            throttle_reasons: ok_if_supported(self.current_throttle_reasons())?,
+            utilization: ok_if_supported(self.utilization_rates())?,
+        })
+    }
+
+    /**
+    Gets whether or not persistent mode is enabled for this `Device`.
+
+    When driver persistence mode is enabled the driver software is not torn down
+    when the last client disconnects. This feature is disabled by default.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    // Checked against local
+    // Tested
+    #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetPersistenceMode")]
     pub fn is_in_persistent_mode(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPersistenceMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPersistenceMode.as_ref(),
+            "nvmlDeviceGetPersistenceMode",
+        )?;
 
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
@@ -3209,6 +4564,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetPowerManagementDefaultLimit
                 .as_ref(),
+            "nvmlDeviceGetPowerManagementDefaultLimit",
         )?;
 
         unsafe {
@@ -3219,6 +4575,39 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the per-domain utilization that this `Device` uses to drive P-State
+    changes.
+
+    This is a finer-grained view than `.utilization_rates()`: utilization is
+    reported separately for the graphics, frame buffer, video and bus domains.
+    See `DynamicPstatesInfo.domain()`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlDeviceGetDynamicPstatesInfo")]
+    pub fn dynamic_pstates_info(&self) -> Result<DynamicPstatesInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetDynamicPstatesInfo.as_ref(),
+            "nvmlDeviceGetDynamicPstatesInfo",
+        )?;
+
+        unsafe {
+            let mut info: nvmlGpuDynamicPstatesInfo_t = mem::zeroed();
+            nvml_try(sym(self.device, &mut info))?;
+
+            Ok(info.into())
+        }
+    }
+
     /**
     Gets the power management limit associated with this `Device`.
 
@@ -3245,7 +4634,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetPowerManagementLimit")]
     pub fn power_management_limit(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPowerManagementLimit.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPowerManagementLimit.as_ref(),
+            "nvmlDeviceGetPowerManagementLimit",
+        )?;
 
         unsafe {
             let mut limit: c_uint = mem::zeroed();
@@ -3281,6 +4673,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetPowerManagementLimitConstraints
                 .as_ref(),
+            "nvmlDeviceGetPowerManagementLimitConstraints",
         )?;
 
         unsafe {
@@ -3298,11 +4691,18 @@ impl<'nvml> Device<'nvml> {
 
     /// Not documenting this because it's deprecated. Read NVIDIA's docs if you
     /// must use it.
+    ///
+    /// Devices that don't support power management return `NotSupported`
+    /// rather than `Ok(false)`; match on that error to tell them apart from
+    /// devices on which power management is supported but disabled.
     // Tested
     #[deprecated(note = "NVIDIA states that \"this API has been deprecated.\"")]
     #[doc(alias = "nvmlDeviceGetPowerManagementMode")]
     pub fn is_power_management_algo_active(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPowerManagementMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPowerManagementMode.as_ref(),
+            "nvmlDeviceGetPowerManagementMode",
+        )?;
 
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
@@ -3318,7 +4718,10 @@ impl<'nvml> Device<'nvml> {
     #[deprecated(note = "use `.performance_state()`.")]
     #[doc(alias = "nvmlDeviceGetPowerState")]
     pub fn power_state(&self) -> Result<PerformanceState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPowerState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPowerState.as_ref(),
+            "nvmlDeviceGetPowerState",
+        )?;
 
         unsafe {
             let mut state: nvmlPstates_t = mem::zeroed();
@@ -3345,13 +4748,15 @@ impl<'nvml> Device<'nvml> {
 
     This reading is accurate to within +/- 5% of current power draw on Fermi and Kepler GPUs.
     It is only supported if power management mode is supported. See `.is_power_management_algo_active()`.
-    Yes, that is deprecated, but that's what NVIDIA's docs say to see.
     */
     // Checked against local
     // Tested
     #[doc(alias = "nvmlDeviceGetPowerUsage")]
     pub fn power_usage(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPowerUsage.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPowerUsage.as_ref(),
+            "nvmlDeviceGetPowerUsage",
+        )?;
 
         unsafe {
             let mut usage: c_uint = mem::zeroed();
@@ -3379,7 +4784,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetTotalEnergyConsumption")]
     pub fn total_energy_consumption(&self) -> Result<u64, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTotalEnergyConsumption.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTotalEnergyConsumption.as_ref(),
+            "nvmlDeviceGetTotalEnergyConsumption",
+        )?;
 
         unsafe {
             let mut total: c_ulonglong = mem::zeroed();
@@ -3413,7 +4821,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetRetiredPages_v2")]
     pub fn retired_pages(&self, cause: RetirementCause) -> Result<Vec<RetiredPage>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetRetiredPages_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetRetiredPages_v2.as_ref(),
+            "nvmlDeviceGetRetiredPages_v2",
+        )?;
 
         unsafe {
             let mut count = match self.retired_pages_count(&cause)? {
@@ -3441,7 +4852,10 @@ impl<'nvml> Device<'nvml> {
 
     // Helper for the above function. Returns # of samples that can be queried.
     fn retired_pages_count(&self, cause: &RetirementCause) -> Result<c_uint, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetRetiredPages.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetRetiredPages.as_ref(),
+            "nvmlDeviceGetRetiredPages",
+        )?;
 
         unsafe {
             let mut count: c_uint = 0;
@@ -3484,6 +4898,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetRetiredPagesPendingStatus
                 .as_ref(),
+            "nvmlDeviceGetRetiredPagesPendingStatus",
         )?;
 
         unsafe {
@@ -3495,6 +4910,87 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the number of memory rows that have been remapped on this `Device`,
+    and whether any remappings are pending or have failed.
+
+    A pending remapping takes effect after the next `Device` reset; until then
+    error containment and dynamic page blacklisting keep it from affecting
+    work on the `Device`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature or MIG is
+      enabled with active instances
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetRemappedRows")]
+    pub fn remapped_rows(&self) -> Result<RemappedRows, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetRemappedRows.as_ref(),
+            "nvmlDeviceGetRemappedRows",
+        )?;
+
+        unsafe {
+            let mut corrected_rows: c_uint = 0;
+            let mut uncorrected_rows: c_uint = 0;
+            let mut is_pending: c_uint = 0;
+            let mut failure_occurred: c_uint = 0;
+
+            nvml_try(sym(
+                self.device,
+                &mut corrected_rows,
+                &mut uncorrected_rows,
+                &mut is_pending,
+                &mut failure_occurred,
+            ))?;
+
+            Ok(RemappedRows {
+                corrected_rows,
+                uncorrected_rows,
+                is_pending: is_pending != 0,
+                failure_occurred: failure_occurred != 0,
+            })
+        }
+    }
+
+    /**
+    Checks whether this `Device`'s memory health suggests it should be
+    serviced, based on `.remapped_rows()`.
+
+    This is a heuristic following NVIDIA's row remapping guidance: it returns
+    `true` if any rows were remapped due to uncorrectable errors, if a
+    remapping is pending (the `Device` needs a reset), or if a remapping has
+    ever failed. A failed remapping is the case NVIDIA recommends replacing
+    the `Device` for; the others may be resolved by a reset, so check the
+    `RemappedRows` fields directly when you need to tell them apart.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support row remapping or MIG is
+      enabled with active instances
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+    */
+    pub fn needs_replacement(&self) -> Result<bool, NvmlError> {
+        let rows = self.remapped_rows()?;
+
+        Ok(rows.uncorrected_rows > 0 || rows.is_pending || rows.failure_occurred)
+    }
+
     /**
     Gets recent samples for this `Device`.
 
@@ -3561,7 +5057,10 @@ impl<'nvml> Device<'nvml> {
         T: Into<Option<u64>>,
     {
         let timestamp = last_seen_timestamp.into().unwrap_or(0);
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSamples.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSamples.as_ref(),
+            "nvmlDeviceGetSamples",
+        )?;
 
         unsafe {
             let mut val_type: nvmlValueType_t = mem::zeroed();
@@ -3592,7 +5091,10 @@ impl<'nvml> Device<'nvml> {
 
     // Helper for the above function. Returns # of samples that can be queried.
     fn samples_count(&self, sample_type: &Sampling, timestamp: u64) -> Result<c_uint, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSamples.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSamples.as_ref(),
+            "nvmlDeviceGetSamples",
+        )?;
 
         unsafe {
             let mut val_type: nvmlValueType_t = mem::zeroed();
@@ -3612,6 +5114,50 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the power samples buffered by the driver for this `Device` since
+    `last_seen_timestamp`.
+
+    The driver keeps a ring buffer of power readings taken more often than a
+    typical poller runs, so draining it with the timestamp of the last sample
+    you saw captures short spikes that `.power_usage()` would miss. Pass `None`
+    to get every buffered sample.
+
+    Returns an empty `Vec` if there are no new samples.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this query is not supported by this `Device`
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, check that error's docs for more info
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Kepler and newer fully supported devices.
+    */
+    pub fn power_samples_since<T>(
+        &self,
+        last_seen_timestamp: T,
+    ) -> Result<Vec<PowerSample>, NvmlError>
+    where
+        T: Into<Option<u64>>,
+    {
+        match self.samples(Sampling::Power, last_seen_timestamp) {
+            Ok(samples) => Ok(samples
+                .into_iter()
+                .map(|s| PowerSample {
+                    timestamp: s.timestamp,
+                    milliwatts: sample_value_as_u64(&s.value) as u32,
+                })
+                .collect()),
+            Err(NvmlError::NotFound) => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+
     /**
     Get values for the given slice of `FieldId`s.
 
@@ -3645,7 +5191,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         id_slice: &[FieldId],
     ) -> Result<Vec<Result<FieldValueSample, NvmlError>>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetFieldValues.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFieldValues.as_ref(),
+            "nvmlDeviceGetFieldValues",
+        )?;
 
         unsafe {
             let values_count = id_slice.len();
@@ -3671,6 +5220,137 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Reads the given `FieldId` now and returns its per-second rate of change
+    since `prev`, an earlier reading of the same field.
+
+    The rate is computed from the driver's μs timestamps embedded in both
+    samples rather than from wall-clock time on the caller's side. Integer
+    values are subtracted exactly before being converted to `f64`. This is
+    intended for cumulative counters such as NVLink or PCIe byte counts.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid, if `prev` is for a different
+      field than `id`, if `prev` holds an error instead of a value, or if `prev`
+      is not older than the new reading
+    * `NotSupported`, if this `Device` doesn't support the given field
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, check that error's docs for more info
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Device support varies per `FieldId` that you pass in.
+    */
+    #[doc(alias = "nvmlDeviceGetFieldValues")]
+    pub fn field_value_rate(&self, id: FieldId, prev: &FieldValueSample) -> Result<f64, NvmlError> {
+        if prev.field != id {
+            return Err(NvmlError::InvalidArg);
+        }
+        let prev_value = prev.value.as_ref().map_err(|_| NvmlError::InvalidArg)?;
+
+        let now = self
+            .field_values_for(&[id])?
+            .pop()
+            .ok_or(NvmlError::Unknown)??;
+        let now_value = now.value?;
+
+        sample_value_rate(prev_value, prev.timestamp, &now_value, now.timestamp)
+    }
+
+    /**
+    Gets the total amount of NVLink data transmitted and received by this
+    `Device`, summed across all of its links.
+
+    This queries the `NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_TX` and
+    `NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_RX` field values with NVML's
+    "all links" scope, so protocol overhead is not included. The counters are
+    cumulative; diff two readings to get a rate.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support NVLink throughput counters
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, check that error's docs for more info
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Volta or newer fully supported devices with NVLink.
+    */
+    #[doc(alias = "nvmlDeviceGetFieldValues")]
+    pub fn nvlink_throughput(&self) -> Result<NvLinkThroughput, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFieldValues.as_ref(),
+            "nvmlDeviceGetFieldValues",
+        )?;
+
+        unsafe {
+            let mut field_values: [nvmlFieldValue_t; 2] = mem::zeroed();
+
+            for (raw, id) in field_values.iter_mut().zip([
+                field_id::NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_TX,
+                field_id::NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_RX,
+            ]) {
+                raw.fieldId = crate::translate_field_id(self.nvml.field_id_scheme, id);
+                // A scope of `UINT_MAX` sums the counter across all links
+                raw.scopeId = c_uint::MAX;
+            }
+
+            nvml_try(sym(self.device, 2, field_values.as_mut_ptr()))?;
+
+            let [tx, rx] = field_values;
+            let tx = FieldValueSample::try_from(tx)?.value?;
+            let rx = FieldValueSample::try_from(rx)?.value?;
+
+            Ok(NvLinkThroughput {
+                tx_kib: sample_value_as_u64(&tx),
+                rx_kib: sample_value_as_u64(&rx),
+            })
+        }
+    }
+
+    /**
+    Gets the action required to recover this `Device` from its current state.
+
+    This reads the `NVML_FI_DEV_GET_GPU_RECOVERY_ACTION` field value, which
+    supersedes the deprecated reset and drain-and-reset status fields.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't report a recovery action
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetFieldValues")]
+    pub fn recovery_action(&self) -> Result<RecoveryAction, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetFieldValues.as_ref(),
+            "nvmlDeviceGetFieldValues",
+        )?;
+
+        unsafe {
+            let mut raw: nvmlFieldValue_t = mem::zeroed();
+            raw.fieldId = crate::translate_field_id(
+                self.nvml.field_id_scheme,
+                field_id::NVML_FI_DEV_GET_GPU_RECOVERY_ACTION,
+            );
+
+            nvml_try(sym(self.device, 1, &mut raw))?;
+
+            let value = FieldValueSample::try_from(raw)?.value?;
+
+            RecoveryAction::try_from(sample_value_as_u64(&value) as u32)
+        }
+    }
+
     /**
     Gets the globally unique board serial number associated with this `Device`'s board
     as an alphanumeric string.
@@ -3694,19 +5374,35 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetSerial")]
     pub fn serial(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSerial.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSerial.as_ref(),
+            "nvmlDeviceGetSerial",
+        )?;
 
         unsafe {
-            let mut serial_vec = vec![0; NVML_DEVICE_SERIAL_BUFFER_SIZE as usize];
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_SERIAL_BUFFER_SIZE)?;
+            Ok(raw.to_str()?.into())
+        }
+    }
 
-            nvml_try(sym(
-                self.device,
-                serial_vec.as_mut_ptr(),
-                NVML_DEVICE_SERIAL_BUFFER_SIZE,
-            ))?;
+    /**
+    Like `.serial()`, but replaces any invalid UTF-8 in the string obtained from
+    the C function with `U+FFFD REPLACEMENT CHARACTER` instead of erroring.
+
+    # Errors
+
+    The same as `.serial()`, except that `Utf8Error` is never returned.
+    */
+    #[doc(alias = "nvmlDeviceGetSerial")]
+    pub fn serial_lossy(&self) -> Result<String, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSerial.as_ref(),
+            "nvmlDeviceGetSerial",
+        )?;
 
-            let serial_raw = CStr::from_ptr(serial_vec.as_ptr());
-            Ok(serial_raw.to_str()?.into())
+        unsafe {
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_SERIAL_BUFFER_SIZE)?;
+            Ok(raw.to_string_lossy().into_owned())
         }
     }
 
@@ -3727,7 +5423,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceGetBoardPartNumber")]
     pub fn board_part_number(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetBoardPartNumber.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetBoardPartNumber.as_ref(),
+            "nvmlDeviceGetBoardPartNumber",
+        )?;
 
         unsafe {
             let mut part_num_vec = vec![0; NVML_DEVICE_PART_NUMBER_BUFFER_SIZE as usize];
@@ -3806,6 +5505,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetCurrentClocksThrottleReasons
                 .as_ref(),
+            "nvmlDeviceGetCurrentClocksThrottleReasons",
         )?;
 
         unsafe {
@@ -3886,6 +5586,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetSupportedClocksThrottleReasons
                 .as_ref(),
+            "nvmlDeviceGetSupportedClocksThrottleReasons",
         )?;
         unsafe {
             let mut reasons: c_ulonglong = mem::zeroed();
@@ -3936,7 +5637,10 @@ impl<'nvml> Device<'nvml> {
         let mut items: Vec<c_uint> = vec![0; size];
         let mut count = size as c_uint;
 
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSupportedGraphicsClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSupportedGraphicsClocks.as_ref(),
+            "nvmlDeviceGetSupportedGraphicsClocks",
+        )?;
 
         unsafe {
             nvml_try_count(sym(
@@ -3985,7 +5689,10 @@ impl<'nvml> Device<'nvml> {
         let mut items: Vec<c_uint> = vec![0; size];
         let mut count = size as c_uint;
 
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSupportedMemoryClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSupportedMemoryClocks.as_ref(),
+            "nvmlDeviceGetSupportedMemoryClocks",
+        )?;
         // TODO: should this fn call `sym` twice, first to populate `count` and second to fill the vec?
         unsafe {
             match sym(self.device, &mut count, items.as_mut_ptr()) {
@@ -4002,21 +5709,59 @@ impl<'nvml> Device<'nvml> {
     }
 
     /**
-    Gets the current temperature readings for the given sensor, in °C.
+    Checks whether the given memory and graphics clock pair (in MHz) is a valid
+    combination to pass to [`Self::set_applications_clocks()`].
+
+    This is built on top of [`Self::supported_memory_clocks()`] and
+    [`Self::supported_graphics_clocks()`]; it returns `false` rather than an
+    error if `mem_clock` is not a supported memory clock.
 
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
-    * `InvalidArg`, if this `Device` is invalid or `sensor` is invalid (shouldn't occur?)
-    * `NotSupported`, if this `Device` does not have the specified sensor
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
     * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
     * `Unknown`, on any unexpected error
-    */
+
+    # Device Support
+
+    Supports Kepler and newer fully supported devices.
+    */
+    // Tested
+    pub fn is_applications_clock_pair_supported(
+        &self,
+        mem_clock: u32,
+        graphics_clock: u32,
+    ) -> Result<bool, NvmlError> {
+        if !self.supported_memory_clocks()?.contains(&mem_clock) {
+            return Ok(false);
+        }
+
+        Ok(self
+            .supported_graphics_clocks(mem_clock)?
+            .contains(&graphics_clock))
+    }
+
+    /**
+    Gets the current temperature readings for the given sensor, in °C.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid or `sensor` is invalid (shouldn't occur?)
+    * `NotSupported`, if this `Device` does not have the specified sensor
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
     // Checked against local
     // Tested
     #[doc(alias = "nvmlDeviceGetTemperature")]
     pub fn temperature(&self, sensor: TemperatureSensor) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTemperature.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTemperature.as_ref(),
+            "nvmlDeviceGetTemperature",
+        )?;
 
         unsafe {
             let mut temp: c_uint = mem::zeroed();
@@ -4027,6 +5772,80 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets every thermal sensor on this `Device` along with its current reading.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    // Checked against local
+    #[doc(alias = "nvmlDeviceGetThermalSettings")]
+    pub fn thermal_settings(&self) -> Result<Vec<ThermalSensor>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetThermalSettings.as_ref(),
+            "nvmlDeviceGetThermalSettings",
+        )?;
+
+        unsafe {
+            let mut settings: nvmlGpuThermalSettings_t = mem::zeroed();
+            // Asking for the "all" target returns every sensor at once
+            let sensor_index = ThermalTarget::All.as_c() as c_uint;
+            nvml_try(sym(self.device, sensor_index, &mut settings))?;
+
+            let count = (settings.count as usize).min(settings.sensor.len());
+            Ok(settings.sensor[..count]
+                .iter()
+                .map(|sensor| ThermalSensor::from(*sensor))
+                .collect())
+        }
+    }
+
+    /**
+    Gets the current temperature of every thermal sensor on this `Device` in °C,
+    keyed by the component each sensor measures.
+
+    Sensors without a meaningful target are skipped. If more than one sensor
+    measures the same target, only the reading from the last of them (in the
+    order `.thermal_settings()` returns them) is kept; use
+    `.thermal_settings()` directly to see every sensor.
+
+    If this `Device` doesn't support `.thermal_settings()`, only the GPU
+    temperature from `.temperature()` is returned, and an empty map if that
+    isn't supported either.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    */
+    pub fn temperatures(&self) -> Result<BTreeMap<ThermalTarget, i32>, NvmlError> {
+        match self.thermal_settings() {
+            Ok(sensors) => Ok(sensors
+                .into_iter()
+                .filter(|s| {
+                    !matches!(
+                        s.target,
+                        ThermalTarget::None | ThermalTarget::All | ThermalTarget::Unknown
+                    )
+                })
+                .map(|s| (s.target, s.current_temp))
+                .collect()),
+            Err(NvmlError::NotSupported) => match self.temperature(TemperatureSensor::Gpu) {
+                Ok(temp) => Ok(BTreeMap::from([(ThermalTarget::Gpu, temp as i32)])),
+                Err(NvmlError::NotSupported) => Ok(BTreeMap::new()),
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
     /**
     Gets the temperature threshold for this `Device` and the specified `threshold_type`, in °C.
 
@@ -4049,7 +5868,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         threshold_type: TemperatureThreshold,
     ) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTemperatureThreshold.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTemperatureThreshold.as_ref(),
+            "nvmlDeviceGetTemperatureThreshold",
+        )?;
 
         unsafe {
             let mut temp: c_uint = mem::zeroed();
@@ -4084,7 +5906,10 @@ impl<'nvml> Device<'nvml> {
         threshold_type: TemperatureThreshold,
         temp: i32,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetTemperatureThreshold.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetTemperatureThreshold.as_ref(),
+            "nvmlDeviceSetTemperatureThreshold",
+        )?;
 
         unsafe {
             let mut t = temp;
@@ -4114,7 +5939,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         other_device: Device,
     ) -> Result<TopologyLevel, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTopologyCommonAncestor.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTopologyCommonAncestor.as_ref(),
+            "nvmlDeviceGetTopologyCommonAncestor",
+        )?;
 
         unsafe {
             let mut level: nvmlGpuTopologyLevel_t = mem::zeroed();
@@ -4146,7 +5974,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         level: TopologyLevel,
     ) -> Result<Vec<Device<'nvml>>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTopologyNearestGpus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTopologyNearestGpus.as_ref(),
+            "nvmlDeviceGetTopologyNearestGpus",
+        )?;
 
         unsafe {
             let mut count = match self.top_nearest_gpus_count(&level)? {
@@ -4172,7 +6003,10 @@ impl<'nvml> Device<'nvml> {
     // Helper for the above function. Returns # of GPUs in the set.
     #[cfg(target_os = "linux")]
     fn top_nearest_gpus_count(&self, level: &TopologyLevel) -> Result<c_uint, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTopologyNearestGpus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTopologyNearestGpus.as_ref(),
+            "nvmlDeviceGetTopologyNearestGpus",
+        )?;
 
         unsafe {
             let mut count: c_uint = 0;
@@ -4218,7 +6052,10 @@ impl<'nvml> Device<'nvml> {
         error_type: MemoryError,
         counter_type: EccCounter,
     ) -> Result<u64, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetTotalEccErrors.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetTotalEccErrors.as_ref(),
+            "nvmlDeviceGetTotalEccErrors",
+        )?;
 
         unsafe {
             let mut count: c_ulonglong = mem::zeroed();
@@ -4274,20 +6111,62 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetUUID")]
     pub fn uuid(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetUUID.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetUUID.as_ref(),
+            "nvmlDeviceGetUUID",
+        )?;
+
+        unsafe {
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_UUID_V2_BUFFER_SIZE)?;
+            Ok(raw.to_str()?.into())
+        }
+    }
+
+    /**
+    Like `.uuid()`, but replaces any invalid UTF-8 in the string obtained from
+    the C function with `U+FFFD REPLACEMENT CHARACTER` instead of erroring.
+
+    # Errors
+
+    The same as `.uuid()`, except that `Utf8Error` is never returned.
+    */
+    #[doc(alias = "nvmlDeviceGetUUID")]
+    pub fn uuid_lossy(&self) -> Result<String, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetUUID.as_ref(),
+            "nvmlDeviceGetUUID",
+        )?;
 
         unsafe {
-            let mut uuid_vec = vec![0; NVML_DEVICE_UUID_V2_BUFFER_SIZE as usize];
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_UUID_V2_BUFFER_SIZE)?;
+            Ok(raw.to_string_lossy().into_owned())
+        }
+    }
 
-            nvml_try(sym(
-                self.device,
-                uuid_vec.as_mut_ptr(),
-                NVML_DEVICE_UUID_V2_BUFFER_SIZE,
-            ))?;
+    /**
+    Gets the globally unique immutable UUID associated with this `Device`,
+    querying NVML only on the first call.
+
+    The UUID never changes for the lifetime of a handle, so this is a cheaper
+    alternative to `.uuid()` for callers that need it repeatedly (e.g. to tag
+    every metric in a telemetry loop). Errors are not cached; a failed query
+    will be retried on the next call.
+
+    # Errors
 
-            let uuid_raw = CStr::from_ptr(uuid_vec.as_ptr());
-            Ok(uuid_raw.to_str()?.into())
+    Same as `.uuid()`.
+    */
+    pub fn cached_uuid(&self) -> Result<String, NvmlError> {
+        let mut cached = self.uuid.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(uuid) = cached.as_ref() {
+            return Ok(uuid.clone());
         }
+
+        let uuid = self.uuid()?;
+        *cached = Some(uuid.clone());
+
+        Ok(uuid)
     }
 
     /**
@@ -4313,7 +6192,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetUtilizationRates")]
     pub fn utilization_rates(&self) -> Result<Utilization, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetUtilizationRates.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetUtilizationRates.as_ref(),
+            "nvmlDeviceGetUtilizationRates",
+        )?;
 
         unsafe {
             let mut utilization: nvmlUtilization_t = mem::zeroed();
@@ -4323,10 +6205,87 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the utilization rates for this `Device`'s major subsystems averaged
+    over `duration`.
+
+    This blocks for `duration`. If this `Device` supports sampling, the samples
+    collected by the driver during that window are averaged; otherwise
+    `.utilization_rates()` is polled every 100ms over the window and the
+    readings are averaged. If the window yields no readings at all (e.g. a
+    zero `duration`), a single `.utilization_rates()` snapshot is returned.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` does not support this feature
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, check that error's docs for more info
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Fermi and newer fully supported devices.
+    */
+    pub fn average_utilization(&self, duration: Duration) -> Result<Utilization, NvmlError> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        match self.samples(Sampling::GpuUtilization, None) {
+            Ok(_) | Err(NvmlError::NotFound) => (),
+            Err(NvmlError::NotSupported)
+            | Err(NvmlError::FunctionNotFound)
+            | Err(NvmlError::FailedToLoadSymbol { .. }) => {
+                let start = Instant::now();
+                let mut gpu = vec![];
+                let mut memory = vec![];
+
+                while start.elapsed() < duration {
+                    let utilization = self.utilization_rates()?;
+                    gpu.push(utilization.gpu as u64);
+                    memory.push(utilization.memory as u64);
+
+                    thread::sleep(POLL_INTERVAL.min(duration.saturating_sub(start.elapsed())));
+                }
+
+                return match (mean(&gpu), mean(&memory)) {
+                    (Some(gpu), Some(memory)) => Ok(Utilization { gpu, memory }),
+                    _ => self.utilization_rates(),
+                };
+            }
+            Err(e) => return Err(e),
+        }
+
+        let start = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+
+        thread::sleep(duration);
+
+        let window = |sampling| match self.samples(sampling, start) {
+            Ok(samples) => Ok(samples
+                .into_iter()
+                .map(|s| sample_value_as_u64(&s.value))
+                .collect::<Vec<_>>()),
+            Err(NvmlError::NotFound) => Ok(vec![]),
+            Err(e) => Err(e),
+        };
+
+        match (
+            mean(&window(Sampling::GpuUtilization)?),
+            mean(&window(Sampling::MemoryUtilization)?),
+        ) {
+            (Some(gpu), Some(memory)) => Ok(Utilization { gpu, memory }),
+            _ => self.utilization_rates(),
+        }
+    }
+
     /**
     Gets the VBIOS version of this `Device`.
 
-    The VBIOS version may change from time to time.
+    The VBIOS version may change from time to time. Wrap the returned string in
+    a `VbiosVersion` if you need to compare versions.
 
     # Errors
 
@@ -4341,19 +6300,35 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetVbiosVersion")]
     pub fn vbios_version(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetVbiosVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVbiosVersion.as_ref(),
+            "nvmlDeviceGetVbiosVersion",
+        )?;
 
         unsafe {
-            let mut version_vec = vec![0; NVML_DEVICE_VBIOS_VERSION_BUFFER_SIZE as usize];
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_VBIOS_VERSION_BUFFER_SIZE)?;
+            Ok(raw.to_str()?.into())
+        }
+    }
 
-            nvml_try(sym(
-                self.device,
-                version_vec.as_mut_ptr(),
-                NVML_DEVICE_VBIOS_VERSION_BUFFER_SIZE,
-            ))?;
+    /**
+    Like `.vbios_version()`, but replaces any invalid UTF-8 in the string obtained from
+    the C function with `U+FFFD REPLACEMENT CHARACTER` instead of erroring.
 
-            let version_raw = CStr::from_ptr(version_vec.as_ptr());
-            Ok(version_raw.to_str()?.into())
+    # Errors
+
+    The same as `.vbios_version()`, except that `Utf8Error` is never returned.
+    */
+    #[doc(alias = "nvmlDeviceGetVbiosVersion")]
+    pub fn vbios_version_lossy(&self) -> Result<String, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVbiosVersion.as_ref(),
+            "nvmlDeviceGetVbiosVersion",
+        )?;
+
+        unsafe {
+            let raw = query_c_string(self.device, *sym, NVML_DEVICE_VBIOS_VERSION_BUFFER_SIZE)?;
+            Ok(raw.to_string_lossy().into_owned())
         }
     }
 
@@ -4385,7 +6360,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         perf_policy: PerformancePolicy,
     ) -> Result<ViolationTime, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetViolationStatus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetViolationStatus.as_ref(),
+            "nvmlDeviceGetViolationStatus",
+        )?;
         unsafe {
             let mut viol_time: nvmlViolationTime_t = mem::zeroed();
 
@@ -4395,6 +6373,46 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Gets the reasons this `Device` is currently throttling along with the
+    cumulative time it has spent throttled by each `PerformancePolicy`.
+
+    This combines `.current_throttle_reasons()` with `.violation_status()` for
+    every policy. Durations that this `Device` cannot report are `None` rather
+    than failing the whole call.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` cannot report throttle reasons
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports all _fully supported_ devices.
+    */
+    pub fn throttling_report(&self) -> Result<ThrottlingReport, NvmlError> {
+        let violation_ns = |policy| match self.violation_status(policy) {
+            Ok(time) => Ok(Some(time.violation_time)),
+            Err(NvmlError::NotSupported) => Ok(None),
+            Err(e) => Err(e),
+        };
+
+        Ok(ThrottlingReport {
+            active_reasons: self.current_throttle_reasons()?,
+            power_ns: violation_ns(PerformancePolicy::Power)?,
+            thermal_ns: violation_ns(PerformancePolicy::Thermal)?,
+            sync_boost_ns: violation_ns(PerformancePolicy::SyncBoost)?,
+            board_limit_ns: violation_ns(PerformancePolicy::BoardLimit)?,
+            low_utilization_ns: violation_ns(PerformancePolicy::LowUtilization)?,
+            reliability_ns: violation_ns(PerformancePolicy::Reliability)?,
+            total_app_clocks_ns: violation_ns(PerformancePolicy::TotalAppClocks)?,
+            total_base_clocks_ns: violation_ns(PerformancePolicy::TotalBaseClocks)?,
+        })
+    }
+
     /**
     Gets the interrupt number for this [`Device`].
 
@@ -4406,7 +6424,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetIrqNum")]
     pub fn irq_num(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetIrqNum.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetIrqNum.as_ref(),
+            "nvmlDeviceGetIrqNum",
+        )?;
 
         let irq_num = unsafe {
             let mut irq_num: c_uint = mem::zeroed();
@@ -4433,7 +6454,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetNumGpuCores")]
     pub fn num_cores(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetNumGpuCores.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetNumGpuCores.as_ref(),
+            "nvmlDeviceGetNumGpuCores",
+        )?;
 
         unsafe {
             let mut count: c_uint = mem::zeroed();
@@ -4459,7 +6483,10 @@ impl<'nvml> Device<'nvml> {
         device2: &Device,
         p2p_index: P2pCapabilitiesIndex,
     ) -> Result<P2pStatus, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetP2PStatus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetP2PStatus.as_ref(),
+            "nvmlDeviceGetP2PStatus",
+        )?;
 
         let status_c = unsafe {
             let mut status: nvmlGpuP2PStatus_t = mem::zeroed();
@@ -4484,7 +6511,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetPowerSource")]
     pub fn power_source(&self) -> Result<PowerSource, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPowerSource.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPowerSource.as_ref(),
+            "nvmlDeviceGetPowerSource",
+        )?;
 
         let power_source_c = unsafe {
             let mut power_source: nvmlPowerSource_t = mem::zeroed();
@@ -4510,7 +6540,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetMemoryBusWidth")]
     pub fn memory_bus_width(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMemoryBusWidth.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMemoryBusWidth.as_ref(),
+            "nvmlDeviceGetMemoryBusWidth",
+        )?;
 
         let memory_bus_width = unsafe {
             let mut memory_bus_width: c_uint = mem::zeroed();
@@ -4534,7 +6567,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetPcieLinkMaxSpeed")]
     pub fn max_pcie_link_speed(&self) -> Result<PcieLinkMaxSpeed, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPcieLinkMaxSpeed.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPcieLinkMaxSpeed.as_ref(),
+            "nvmlDeviceGetPcieLinkMaxSpeed",
+        )?;
 
         let pcie_link_max_speed_c = unsafe {
             let mut pcie_link_max_speed: c_uint = mem::zeroed();
@@ -4578,7 +6614,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetPcieSpeed")]
     pub fn pcie_link_speed(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPcieSpeed.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPcieSpeed.as_ref(),
+            "nvmlDeviceGetPcieSpeed",
+        )?;
 
         let pcie_speed_c = unsafe {
             let mut pcie_speed: c_uint = mem::zeroed();
@@ -4600,7 +6639,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetBusType")]
     pub fn bus_type(&self) -> Result<BusType, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetBusType.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetBusType.as_ref(),
+            "nvmlDeviceGetBusType",
+        )?;
 
         let bus_type_c = unsafe {
             let mut bus_type: nvmlBusType_t = mem::zeroed();
@@ -4616,13 +6658,20 @@ impl<'nvml> Device<'nvml> {
     /**
     Gets the architecture of this [`Device`].
 
+    Architecture values that this wrapper does not recognize (most likely
+    architectures introduced in newer drivers) are reported as
+    `DeviceArchitecture::Unknown` rather than as an error.
+
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
     */
     #[doc(alias = "nvmlDeviceGetArchitecture")]
     pub fn architecture(&self) -> Result<DeviceArchitecture, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetArchitecture.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetArchitecture.as_ref(),
+            "nvmlDeviceGetArchitecture",
+        )?;
 
         let architecture_c = unsafe {
             let mut architecture: nvmlDeviceArchitecture_t = mem::zeroed();
@@ -4632,7 +6681,10 @@ impl<'nvml> Device<'nvml> {
             architecture
         };
 
-        DeviceArchitecture::try_from(architecture_c)
+        match DeviceArchitecture::try_from(architecture_c) {
+            Err(NvmlError::UnexpectedVariant(_)) => Ok(DeviceArchitecture::Unknown),
+            result => result,
+        }
     }
 
     /**
@@ -4650,7 +6702,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceOnSameBoard")]
     pub fn is_on_same_board_as(&self, other_device: &Device) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceOnSameBoard.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceOnSameBoard.as_ref(),
+            "nvmlDeviceOnSameBoard",
+        )?;
 
         unsafe {
             let mut bool_int: c_int = mem::zeroed();
@@ -4694,7 +6749,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceResetApplicationsClocks")]
     pub fn reset_applications_clocks(&mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceResetApplicationsClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceResetApplicationsClocks.as_ref(),
+            "nvmlDeviceResetApplicationsClocks",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
@@ -4711,7 +6769,7 @@ impl<'nvml> Device<'nvml> {
     auto boost behavior.
 
     Non-root users may use this API by default, but access can be restricted by root using
-    `.set_api_restriction()`.
+    `.set_api_restricted()`.
 
     Note: persistence mode is required to modify the curent auto boost settings and
     therefore must be enabled.
@@ -4734,7 +6792,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetAutoBoostedClocksEnabled")]
     pub fn set_auto_boosted_clocks(&mut self, enabled: bool) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetAutoBoostedClocksEnabled.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetAutoBoostedClocksEnabled.as_ref(),
+            "nvmlDeviceSetAutoBoostedClocksEnabled",
+        )?;
 
         unsafe { nvml_try(sym(self.device, state_from_bool(enabled))) }
     }
@@ -4766,7 +6827,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceSetCpuAffinity")]
     pub fn set_cpu_affinity(&mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetCpuAffinity.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetCpuAffinity.as_ref(),
+            "nvmlDeviceSetCpuAffinity",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
@@ -4802,7 +6866,10 @@ impl<'nvml> Device<'nvml> {
         size: usize,
         scope: nvmlAffinityScope_t,
     ) -> Result<Vec<c_ulong>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetCpuAffinityWithinScope.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCpuAffinityWithinScope.as_ref(),
+            "nvmlDeviceGetCpuAffinityWithinScope",
+        )?;
 
         unsafe {
             if size == 0 {
@@ -4862,6 +6929,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceSetDefaultAutoBoostedClocksEnabled
                 .as_ref(),
+            "nvmlDeviceSetDefaultAutoBoostedClocksEnabled",
         )?;
 
         unsafe {
@@ -4891,7 +6959,10 @@ impl<'nvml> Device<'nvml> {
     // Tested on machines other than my own
     #[doc(alias = "nvmlDeviceValidateInforom")]
     pub fn validate_info_rom(&self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceValidateInforom.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceValidateInforom.as_ref(),
+            "nvmlDeviceValidateInforom",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
@@ -4919,7 +6990,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceClearAccountingPids")]
     pub fn clear_accounting_pids(&mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceClearAccountingPids.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceClearAccountingPids.as_ref(),
+            "nvmlDeviceClearAccountingPids",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
@@ -4948,7 +7022,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetAccountingBufferSize")]
     pub fn accounting_buffer_size(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAccountingBufferSize.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAccountingBufferSize.as_ref(),
+            "nvmlDeviceGetAccountingBufferSize",
+        )?;
 
         unsafe {
             let mut count: c_uint = mem::zeroed();
@@ -4977,7 +7054,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetAccountingMode")]
     pub fn is_accounting_enabled(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAccountingMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAccountingMode.as_ref(),
+            "nvmlDeviceGetAccountingMode",
+        )?;
 
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
@@ -5006,7 +7086,10 @@ impl<'nvml> Device<'nvml> {
     // Tested
     #[doc(alias = "nvmlDeviceGetAccountingPids")]
     pub fn accounting_pids(&self) -> Result<Vec<u32>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAccountingPids.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAccountingPids.as_ref(),
+            "nvmlDeviceGetAccountingPids",
+        )?;
 
         unsafe {
             let mut count = match self.accounting_pids_count()? {
@@ -5023,7 +7106,10 @@ impl<'nvml> Device<'nvml> {
 
     // Helper function for the above.
     fn accounting_pids_count(&self) -> Result<c_uint, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAccountingPids.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAccountingPids.as_ref(),
+            "nvmlDeviceGetAccountingPids",
+        )?;
 
         // Indicates that we want the count
         let mut count: c_uint = 0;
@@ -5075,7 +7161,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (for error)
     #[doc(alias = "nvmlDeviceGetAccountingStats")]
     pub fn accounting_stats_for(&self, process_id: u32) -> Result<AccountingStats, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetAccountingStats.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetAccountingStats.as_ref(),
+            "nvmlDeviceGetAccountingStats",
+        )?;
 
         unsafe {
             let mut stats: nvmlAccountingStats_t = mem::zeroed();
@@ -5114,7 +7203,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetAccountingMode")]
     pub fn set_accounting(&mut self, enabled: bool) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetAccountingMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetAccountingMode.as_ref(),
+            "nvmlDeviceSetAccountingMode",
+        )?;
 
         unsafe { nvml_try(sym(self.device, state_from_bool(enabled))) }
     }
@@ -5149,11 +7241,36 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceClearEccErrorCounts")]
     pub fn clear_ecc_error_counts(&mut self, counter_type: EccCounter) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceClearEccErrorCounts.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceClearEccErrorCounts.as_ref(),
+            "nvmlDeviceClearEccErrorCounts",
+        )?;
 
         unsafe { nvml_try(sym(self.device, counter_type.as_c())) }
     }
 
+    /**
+    Clears both the volatile and the aggregate ECC error counts for this
+    `Device`.
+
+    Both counter types are cleared even if clearing the first one fails; the
+    first error encountered (if any) is returned.
+
+    Requires root/admin permissions and ECC mode to be enabled.
+
+    # Errors
+
+    Same as `.clear_ecc_error_counts()`.
+    */
+    pub fn clear_all_ecc_counts(&mut self) -> Result<(), NvmlError> {
+        let results = [
+            self.clear_ecc_error_counts(EccCounter::Volatile),
+            self.clear_ecc_error_counts(EccCounter::Aggregate),
+        ];
+
+        results.into_iter().collect()
+    }
+
     /**
     Changes the root/admin restrictions on certain APIs.
 
@@ -5181,7 +7298,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetAPIRestriction")]
     pub fn set_api_restricted(&mut self, api_type: Api, restricted: bool) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetAPIRestriction.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetAPIRestriction.as_ref(),
+            "nvmlDeviceSetAPIRestriction",
+        )?;
 
         unsafe {
             nvml_try(sym(
@@ -5238,7 +7358,10 @@ impl<'nvml> Device<'nvml> {
         mem_clock: u32,
         graphics_clock: u32,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetApplicationsClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetApplicationsClocks.as_ref(),
+            "nvmlDeviceSetApplicationsClocks",
+        )?;
 
         unsafe { nvml_try(sym(self.device, mem_clock, graphics_clock)) }
     }
@@ -5271,7 +7394,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetComputeMode")]
     pub fn set_compute_mode(&mut self, mode: ComputeMode) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetComputeMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetComputeMode.as_ref(),
+            "nvmlDeviceSetComputeMode",
+        )?;
 
         unsafe { nvml_try(sym(self.device, mode.as_c())) }
     }
@@ -5279,7 +7405,8 @@ impl<'nvml> Device<'nvml> {
     /**
     Sets the driver model for this `Device`.
 
-    This operation takes effect after the next reboot. The model may only be
+    This operation takes effect after the next reboot; until then, the new
+    model is reported as `pending` by `.driver_model()`. The model may only be
     set to WDDM when running in DEFAULT compute mode. Changing the model to
     WDDM is not supported when the GPU doesn't support graphics acceleration
     or will not support it after a reboot.
@@ -5339,7 +7466,10 @@ impl<'nvml> Device<'nvml> {
         model: DriverModel,
         flags: Behavior,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetDriverModel.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetDriverModel.as_ref(),
+            "nvmlDeviceSetDriverModel",
+        )?;
 
         unsafe { nvml_try(sym(self.device, model.as_c(), flags.bits())) }
     }
@@ -5375,7 +7505,10 @@ impl<'nvml> Device<'nvml> {
         &mut self,
         setting: GpuLockedClocksSetting,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetGpuLockedClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetGpuLockedClocks.as_ref(),
+            "nvmlDeviceSetGpuLockedClocks",
+        )?;
 
         let (min_clock_mhz, max_clock_mhz) = setting.into_min_and_max_clocks();
 
@@ -5403,7 +7536,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceResetGpuLockedClocks")]
     pub fn reset_gpu_locked_clocks(&mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceResetGpuLockedClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceResetGpuLockedClocks.as_ref(),
+            "nvmlDeviceResetGpuLockedClocks",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
@@ -5432,7 +7568,10 @@ impl<'nvml> Device<'nvml> {
         min_clock_mhz: u32,
         max_clock_mhz: u32,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetMemoryLockedClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetMemoryLockedClocks.as_ref(),
+            "nvmlDeviceSetMemoryLockedClocks",
+        )?;
 
         unsafe { nvml_try(sym(self.device, min_clock_mhz, max_clock_mhz)) }
     }
@@ -5458,17 +7597,54 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceResetMemoryLockedClocks")]
     pub fn reset_mem_locked_clocks(&mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceResetMemoryLockedClocks.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceResetMemoryLockedClocks.as_ref(),
+            "nvmlDeviceResetMemoryLockedClocks",
+        )?;
 
         unsafe { nvml_try(sym(self.device)) }
     }
 
+    /**
+    Return this [`Device`]'s clocks to their default behavior in one call.
+
+    This resets applications clocks, resets locked GPU and memory clocks, and
+    re-enables auto boosted clocks. Every step is attempted even if an earlier
+    one fails; steps that this `Device` doesn't support are skipped.
+
+    ECC error counts are left untouched; use [`Self::clear_ecc_error_counts()`]
+    if you want to clear those as well.
+
+    # Errors
+
+    Returns the first error encountered, which may be any of the errors listed
+    for [`Self::reset_applications_clocks()`], [`Self::reset_gpu_locked_clocks()`],
+    [`Self::reset_mem_locked_clocks()`] and [`Self::set_auto_boosted_clocks()`]
+    other than `NotSupported`.
+    */
+    // Tested (no-run)
+    pub fn reset_to_defaults(&mut self) -> Result<(), NvmlError> {
+        let results = [
+            self.reset_applications_clocks(),
+            self.reset_gpu_locked_clocks(),
+            self.reset_mem_locked_clocks(),
+            self.set_auto_boosted_clocks(true),
+        ];
+
+        results
+            .into_iter()
+            .filter(|r| !matches!(r, Err(NvmlError::NotSupported)))
+            .collect()
+    }
+
     /**
     Set whether or not ECC mode is enabled for this `Device`.
 
     Requires root/admin permissions. Only applicable to devices with ECC.
 
-    This operation takes effect after the next reboot.
+    This operation takes effect after the next reboot. Until then, the new setting
+    is reported in the `pending_enabled` field of `.is_ecc_enabled()` while
+    `currently_enabled` keeps reflecting the active mode.
 
     # Errors
 
@@ -5488,7 +7664,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetEccMode")]
     pub fn set_ecc(&mut self, enabled: bool) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetEccMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetEccMode.as_ref(),
+            "nvmlDeviceSetEccMode",
+        )?;
 
         unsafe { nvml_try(sym(self.device, state_from_bool(enabled))) }
     }
@@ -5522,7 +7701,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetGpuOperationMode")]
     pub fn set_gpu_op_mode(&mut self, mode: OperationMode) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetGpuOperationMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetGpuOperationMode.as_ref(),
+            "nvmlDeviceSetGpuOperationMode",
+        )?;
 
         unsafe { nvml_try(sym(self.device, mode.as_c())) }
     }
@@ -5562,7 +7744,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceSetPersistenceMode")]
     pub fn set_persistent(&mut self, enabled: bool) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetPersistenceMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetPersistenceMode.as_ref(),
+            "nvmlDeviceSetPersistenceMode",
+        )?;
 
         unsafe { nvml_try(sym(self.device, state_from_bool(enabled))) }
     }
@@ -5595,7 +7780,10 @@ impl<'nvml> Device<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlDeviceSetPowerManagementLimit")]
     pub fn set_power_management_limit(&mut self, limit: u32) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetPowerManagementLimit.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetPowerManagementLimit.as_ref(),
+            "nvmlDeviceSetPowerManagementLimit",
+        )?;
 
         unsafe { nvml_try(sym(self.device, limit)) }
     }
@@ -5622,7 +7810,10 @@ impl<'nvml> Device<'nvml> {
         clock_type: Clock,
         power_state: PerformanceState,
     ) -> Result<ClockOffset, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetClockOffsets.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetClockOffsets.as_ref(),
+            "nvmlDeviceGetClockOffsets",
+        )?;
 
         unsafe {
             // Implements NVML_STRUCT_VERSION(ClockOffset, 1), as detailed in nvml.h
@@ -5642,6 +7833,40 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Retrieve min, max and current clock offset of some clock domain for every
+    supported PState.
+
+    The returned `ClockOffset`s are in the same order as
+    `.supported_performance_states()`. PStates for which NVML reports that
+    offsets are not supported are skipped.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if device or type are invalid
+    * `ArgumentVersionMismatch`, if the provided version is invalid/unsupported
+    * `NotSupported`, if this `Device` does not support performance state readings
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetClockOffsets")]
+    pub fn clock_offset_ranges(&self, clock_type: Clock) -> Result<Vec<ClockOffset>, NvmlError> {
+        let mut ranges = vec![];
+
+        for pstate in self.supported_performance_states()? {
+            if let Some(range) = ok_if_supported(self.clock_offset(clock_type, pstate))? {
+                ranges.push(range);
+            }
+        }
+
+        Ok(ranges)
+    }
+
     /**
     Control current clock offset of some clock domain for a given PState
 
@@ -5665,7 +7890,10 @@ impl<'nvml> Device<'nvml> {
         power_state: PerformanceState,
         offset: i32,
     ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetClockOffsets.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetClockOffsets.as_ref(),
+            "nvmlDeviceSetClockOffsets",
+        )?;
 
         unsafe {
             // Implements NVML_STRUCT_VERSION(ClockOffset, 1), as detailed in nvml.h
@@ -5706,6 +7934,7 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetSupportedPerformanceStates
                 .as_ref(),
+            "nvmlDeviceGetSupportedPerformanceStates",
         )?;
 
         unsafe {
@@ -5743,7 +7972,10 @@ impl<'nvml> Device<'nvml> {
         clock_type: Clock,
         pstate: PerformanceState,
     ) -> Result<(u32, u32), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetMinMaxClockOfPState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetMinMaxClockOfPState.as_ref(),
+            "nvmlDeviceGetMinMaxClockOfPState",
+        )?;
 
         unsafe {
             let mut min: u32 = mem::zeroed();
@@ -5839,7 +8071,10 @@ impl<'nvml> Device<'nvml> {
         events: EventTypes,
         set: EventSet<'nvml>,
     ) -> Result<EventSet<'nvml>, NvmlErrorWithSource> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceRegisterEvents.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceRegisterEvents.as_ref(),
+            "nvmlDeviceRegisterEvents",
+        )?;
 
         unsafe {
             match nvml_try(sym(self.device, events.bits(), set.handle())) {
@@ -5956,7 +8191,10 @@ impl<'nvml> Device<'nvml> {
     // Helper for the above methods.
     #[cfg(target_os = "linux")]
     fn supported_event_types_raw(&self) -> Result<c_ulonglong, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSupportedEventTypes.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSupportedEventTypes.as_ref(),
+            "nvmlDeviceGetSupportedEventTypes",
+        )?;
 
         unsafe {
             let mut ev_types: c_ulonglong = mem::zeroed();
@@ -6038,7 +8276,10 @@ impl<'nvml> Device<'nvml> {
             self.pci_info()?
         };
 
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceModifyDrainState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceModifyDrainState.as_ref(),
+            "nvmlDeviceModifyDrainState",
+        )?;
 
         unsafe { nvml_try(sym(&mut pci_info.try_into()?, state_from_bool(enabled))) }
     }
@@ -6105,7 +8346,10 @@ impl<'nvml> Device<'nvml> {
             self.pci_info()?
         };
 
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceQueryDrainState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceQueryDrainState.as_ref(),
+            "nvmlDeviceQueryDrainState",
+        )?;
 
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
@@ -6138,7 +8382,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetPerformanceModes")]
     pub fn performance_modes(&self) -> Result<(Vec<String>, u32), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetPerformanceModes.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetPerformanceModes.as_ref(),
+            "nvmlDeviceGetPerformanceModes",
+        )?;
 
         unsafe {
             let mut pmodes: nvmlDevicePerfModes_t = mem::zeroed();
@@ -6154,6 +8401,48 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Get the current performance mode (clock frequencies) of this `Device`.
+
+    This is the entry of `.performance_modes()` that this `Device` is currently
+    running at, returned as the raw comma-separated `key=value` string that NVML
+    provides (e.g. `"nvclock=324, nvclockmin=324, ..."`).
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Utf8Error`, if the string obtained from the C function is not valid Utf8
+    * `Unknown`, on any unexpected error
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    // Checked against local
+    // Tested
+    #[cfg(target_os = "linux")]
+    #[doc(alias = "nvmlDeviceGetCurrentClockFreqs")]
+    pub fn current_performance_mode(&self) -> Result<String, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCurrentClockFreqs.as_ref(),
+            "nvmlDeviceGetCurrentClockFreqs",
+        )?;
+
+        unsafe {
+            let mut freqs: nvmlDeviceCurrentClockFreqs_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(DeviceCurrentClockFreqs, 1), as detailed in nvml.h
+            freqs.version = (std::mem::size_of::<nvmlDeviceCurrentClockFreqs_v1_t>()
+                | (1_usize << 24_usize)) as u32;
+
+            nvml_try(sym(self.device, &mut freqs))?;
+
+            let freqs_raw = CStr::from_ptr(freqs.str_.as_ptr());
+            Ok(freqs_raw.to_str()?.trim().into())
+        }
+    }
+
     /**
     Gets the active vGPU instances for `Device`
 
@@ -6175,7 +8464,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetActiveVgpus")]
     pub fn active_vgpus(&self) -> Result<Vec<nvmlVgpuInstance_t>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetActiveVgpus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetActiveVgpus.as_ref(),
+            "nvmlDeviceGetActiveVgpus",
+        )?;
 
         unsafe {
             let mut count: u32 = 0;
@@ -6188,6 +8480,45 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Checks whether per-process accounting is enabled on the given vGPU
+    instance.
+
+    Use `.vgpu_accounting_pids()` and `.vgpu_accounting_instance()` to query
+    the accounting data itself.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `NotSupported`, if the vGPU doesn't support this feature
+    * `DriverNotLoaded`, if the NVIDIA driver is not running on the vGPU instance
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell or newer fully supported devices.
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetAccountingMode")]
+    pub fn is_vgpu_accounting_enabled(
+        &self,
+        instance: nvmlVgpuInstance_t,
+    ) -> Result<bool, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetAccountingMode.as_ref(),
+            "nvmlVgpuInstanceGetAccountingMode",
+        )?;
+
+        unsafe {
+            let mut state: nvmlEnableState_t = mem::zeroed();
+            nvml_try(sym(instance, &mut state))?;
+
+            bool_from_state(state)
+        }
+    }
+
     /**
     Get the list of process ids running on a given vGPU instance for stats purpose
 
@@ -6207,7 +8538,10 @@ impl<'nvml> Device<'nvml> {
         &self,
         instance: nvmlVgpuInstance_t,
     ) -> Result<Vec<u32>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlVgpuInstanceGetAccountingPids.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetAccountingPids.as_ref(),
+            "nvmlVgpuInstanceGetAccountingPids",
+        )?;
 
         unsafe {
             let mut count: u32 = 0;
@@ -6225,28 +8559,368 @@ impl<'nvml> Device<'nvml> {
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
-    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+    * `NotSupported`, if the platform does not support this feature
+
+    # Platform Support
+
+    For Maxwell or newer fully supported devices
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetAccountingStats")]
+    pub fn vgpu_accounting_instance(
+        &self,
+        instance: nvmlVgpuInstance_t,
+        pid: u32,
+    ) -> Result<AccountingStats, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetAccountingStats.as_ref(),
+            "nvmlVgpuInstanceGetAccountingStats",
+        )?;
+
+        unsafe {
+            let mut stats: nvmlAccountingStats_t = mem::zeroed();
+            nvml_try(sym(instance, pid, &mut stats))?;
+
+            Ok(AccountingStats::from(stats))
+        }
+    }
+
+    /**
+    Gets the vGPU metadata for the given running vGPU instance and its
+    associated VM.
+
+    Some fields depend on information obtained from the guest VM, which may
+    not be available yet; check `guest_info_initialized` on the returned
+    struct.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `Utf8Error`, if a driver version string could not be interpreted as UTF-8
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetMetadata")]
+    pub fn vgpu_instance_metadata(
+        &self,
+        instance: nvmlVgpuInstance_t,
+    ) -> Result<VgpuMetadata, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetMetadata.as_ref(),
+            "nvmlVgpuInstanceGetMetadata",
+        )?;
+
+        unsafe {
+            let mut size: c_uint = 0;
+
+            nvml_try_count(sym(instance, std::ptr::null_mut(), &mut size))?;
+            let mut buffer: Vec<u32> = vec![0; (size as usize + 3) / 4];
+            nvml_try(sym(
+                instance,
+                buffer.as_mut_ptr() as *mut nvmlVgpuMetadata_t,
+                &mut size,
+            ))?;
+
+            let raw = slice::from_raw_parts(buffer.as_ptr() as *const u8, size as usize);
+            VgpuMetadata::try_from_raw(raw.to_vec())
+        }
+    }
+
+    /**
+    Gets the license information for the given vGPU instance.
+
+    Use this to detect vGPU instances that failed to acquire their license.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetLicenseInfo_v2")]
+    pub fn vgpu_license_info(
+        &self,
+        instance: nvmlVgpuInstance_t,
+    ) -> Result<VgpuLicenseInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetLicenseInfo_v2.as_ref(),
+            "nvmlVgpuInstanceGetLicenseInfo_v2",
+        )?;
+
+        unsafe {
+            let mut info: nvmlVgpuLicenseInfo_t = mem::zeroed();
+            nvml_try(sym(instance, &mut info))?;
+
+            VgpuLicenseInfo::try_from(info)
+        }
+    }
+
+    /**
+    Gets frame buffer capture statistics for the given vGPU instance.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell or newer fully supported devices.
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetFBCStats")]
+    pub fn vgpu_fbc_stats(&self, instance: nvmlVgpuInstance_t) -> Result<FbcStats, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetFBCStats.as_ref(),
+            "nvmlVgpuInstanceGetFBCStats",
+        )?;
+
+        unsafe {
+            let mut fbc_stats: nvmlFBCStats_t = mem::zeroed();
+            nvml_try(sym(instance, &mut fbc_stats))?;
+
+            Ok(fbc_stats.into())
+        }
+    }
+
+    /**
+    Gets information about active frame buffer capture sessions on the given
+    vGPU instance.
+
+    See `.fbc_sessions_info()` for notes on zeroed fields.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `IncorrectBits`, if bits are found in a session's info flags that don't
+      match the flags in this wrapper
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell or newer fully supported devices.
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetFBCSessions")]
+    pub fn vgpu_fbc_sessions(
+        &self,
+        instance: nvmlVgpuInstance_t,
+    ) -> Result<Vec<FbcSessionInfo>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetFBCSessions.as_ref(),
+            "nvmlVgpuInstanceGetFBCSessions",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = 0;
+
+            nvml_try_count(sym(instance, &mut count, ptr::null_mut()))?;
+            if count == 0 {
+                return Ok(vec![]);
+            }
+
+            let mut info: Vec<nvmlFBCSessionInfo_t> = vec![mem::zeroed(); count as usize];
+            nvml_try(sym(instance, &mut count, info.as_mut_ptr()))?;
+
+            info.truncate(count as usize);
+            info.into_iter().map(FbcSessionInfo::try_from).collect()
+        }
+    }
+
+    /**
+    Gets information about active encoder sessions on the given vGPU instance.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `UnexpectedVariant`, if an enum variant not defined in this wrapper gets
+      returned in a field of an `EncoderSessionInfo` struct
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell or newer fully supported devices.
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetEncoderSessions")]
+    pub fn vgpu_encoder_sessions(
+        &self,
+        instance: nvmlVgpuInstance_t,
+    ) -> Result<Vec<EncoderSessionInfo>, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetEncoderSessions.as_ref(),
+            "nvmlVgpuInstanceGetEncoderSessions",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = 0;
+
+            nvml_try_count(sym(instance, &mut count, ptr::null_mut()))?;
+            if count == 0 {
+                return Ok(vec![]);
+            }
+
+            let mut sessions: Vec<nvmlEncoderSessionInfo_t> = vec![mem::zeroed(); count as usize];
+            nvml_try(sym(instance, &mut count, sessions.as_mut_ptr()))?;
+
+            sessions.truncate(count as usize);
+            sessions
+                .into_iter()
+                .map(EncoderSessionInfo::try_from)
+                .collect()
+        }
+    }
+
+    /**
+    Gets the encoder capacity of the given vGPU instance, as a percentage of
+    the maximum encoder capacity (0-100).
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlVgpuInstanceGetEncoderCapacity")]
+    pub fn vgpu_encoder_capacity(&self, instance: nvmlVgpuInstance_t) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceGetEncoderCapacity.as_ref(),
+            "nvmlVgpuInstanceGetEncoderCapacity",
+        )?;
+
+        unsafe {
+            let mut capacity: c_uint = mem::zeroed();
+            nvml_try(sym(instance, &mut capacity))?;
+
+            Ok(capacity)
+        }
+    }
+
+    /**
+    Gets the per-engine utilization of each vGPU instance running on this
+    `Device`.
+
+    Each returned sample splits utilization across the SM, frame buffer,
+    encoder, decoder, JPEG and OFA engines.
+
+    Pass `None` for `last_seen_timestamp` to read utilization based on all
+    the samples maintained by the driver's internal sample buffer, or a
+    timestamp (in μs) from a previous sample to read utilization since then.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if vGPU is not supported by this `Device`
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `ArgumentVersionMismatch`, if the struct version is not supported by the driver
+    * `NotFound`, if sample entries are not found
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetVgpuInstancesUtilizationInfo")]
+    pub fn vgpu_instances_utilization<T>(
+        &self,
+        last_seen_timestamp: T,
+    ) -> Result<Vec<VgpuInstanceUtilizationInfo>, NvmlError>
+    where
+        T: Into<Option<u64>>,
+    {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlDeviceGetVgpuInstancesUtilizationInfo
+                .as_ref(),
+            "nvmlDeviceGetVgpuInstancesUtilizationInfo",
+        )?;
+
+        unsafe {
+            // Implements NVML_STRUCT_VERSION(VgpuInstancesUtilizationInfo, 1), as detailed in nvml.h
+            let version = (mem::size_of::<nvmlVgpuInstancesUtilizationInfo_v1_t>()
+                | (1_usize << 24_usize)) as u32;
+            let last_seen_timestamp = last_seen_timestamp.into().unwrap_or(0);
+
+            let mut info: nvmlVgpuInstancesUtilizationInfo_v1_t = mem::zeroed();
+            info.version = version;
+            info.lastSeenTimeStamp = last_seen_timestamp;
+
+            nvml_try_count(sym(self.device, &mut info))?;
+
+            if info.vgpuInstanceCount == 0 {
+                return Ok(vec![]);
+            }
+
+            let mut samples: Vec<nvmlVgpuInstanceUtilizationInfo_v1_t> =
+                vec![mem::zeroed(); info.vgpuInstanceCount as usize];
+            info.version = version;
+            info.lastSeenTimeStamp = last_seen_timestamp;
+            info.vgpuUtilArray = samples.as_mut_ptr();
+
+            nvml_try(sym(self.device, &mut info))?;
+
+            let tag = SampleValueType::try_from(info.sampleValType)?;
+            Ok(samples
+                .into_iter()
+                .take(info.vgpuInstanceCount as usize)
+                .map(|s| VgpuInstanceUtilizationInfo::from_tag_and_struct(&tag, s))
+                .collect())
+        }
+    }
+
+    /**
+    Sets the encoder capacity of the given vGPU instance, as a percentage of
+    the maximum encoder capacity.
+
+    Use this to cap how much of the encoder a single VM can use.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `instance` is 0 or `percent` is greater than 100
+    * `NotFound`, if `instance` does not match a valid active vGPU instance
     * `Unknown`, on any unexpected error
-    * `NotSupported`, if the platform does not support this feature
 
-    # Platform Support
+    # Device Support
 
-    For Maxwell or newer fully supported devices
+    Supports Maxwell and newer fully supported devices.
     */
-    #[doc(alias = "nvmlVgpuInstanceGetAccountingStats")]
-    pub fn vgpu_accounting_instance(
-        &self,
+    #[doc(alias = "nvmlVgpuInstanceSetEncoderCapacity")]
+    pub fn set_vgpu_encoder_capacity(
+        &mut self,
         instance: nvmlVgpuInstance_t,
-        pid: u32,
-    ) -> Result<AccountingStats, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlVgpuInstanceGetAccountingStats.as_ref())?;
+        percent: u32,
+    ) -> Result<(), NvmlError> {
+        if percent > 100 {
+            return Err(NvmlError::InvalidArg);
+        }
 
-        unsafe {
-            let mut stats: nvmlAccountingStats_t = mem::zeroed();
-            nvml_try(sym(instance, pid, &mut stats))?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlVgpuInstanceSetEncoderCapacity.as_ref(),
+            "nvmlVgpuInstanceSetEncoderCapacity",
+        )?;
 
-            Ok(AccountingStats::from(stats))
-        }
+        unsafe { nvml_try(sym(instance, percent)) }
     }
 
     /**
@@ -6271,7 +8945,10 @@ impl<'nvml> Device<'nvml> {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceGetVirtualizationMode")]
     pub fn virtualization_mode(&self) -> Result<GpuVirtualizationMode, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetVirtualizationMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVirtualizationMode.as_ref(),
+            "nvmlDeviceGetVirtualizationMode",
+        )?;
 
         unsafe {
             let mut mode: nvmlGpuVirtualizationMode_t = mem::zeroed();
@@ -6282,6 +8959,39 @@ impl<'nvml> Device<'nvml> {
         }
     }
 
+    /**
+    Sets the virtualization mode of this `Device`.
+
+    A host GPU typically needs to be in `GpuVirtualizationMode::HostVgpu`
+    before the vGPU type and instance APIs return anything.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if setting the virtualization mode is not supported
+    * `NoPermission`, if setting the virtualization mode is not allowed for this client
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    # Device support
+
+    Supports Kepler and newer fully supported devices.
+    */
+    #[cfg(target_os = "linux")]
+    #[doc(alias = "nvmlDeviceSetVirtualizationMode")]
+    pub fn set_virtualization_mode(
+        &mut self,
+        mode: GpuVirtualizationMode,
+    ) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetVirtualizationMode.as_ref(),
+            "nvmlDeviceSetVirtualizationMode",
+        )?;
+
+        unsafe { nvml_try(sym(self.device, mode.as_c())) }
+    }
+
     /**
     Removes this `Device` from the view of both NVML and the NVIDIA kernel driver.
 
@@ -6417,7 +9127,10 @@ impl<'nvml> Device<'nvml> {
             }
         };
 
-        let sym = match nvml_sym(self.nvml.lib.nvmlDeviceRemoveGpu_v2.as_ref()) {
+        let sym = match nvml_sym(
+            self.nvml.lib.nvmlDeviceRemoveGpu_v2.as_ref(),
+            "nvmlDeviceRemoveGpu_v2",
+        ) {
             Ok(sym) => sym,
             Err(error) => {
                 return (
@@ -6452,7 +9165,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetGspFirmwareMode")]
     pub fn gsp_firmware_mode(&self) -> Result<GspFirmwareMode, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetGspFirmwareMode.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGspFirmwareMode.as_ref(),
+            "nvmlDeviceGetGspFirmwareMode",
+        )?;
 
         unsafe {
             let mut enabled: c_uint = 0;
@@ -6479,7 +9195,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlDeviceGetGspFirmwareVersion")]
     pub fn gsp_firmware_version(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetGspFirmwareVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGspFirmwareVersion.as_ref(),
+            "nvmlDeviceGetGspFirmwareVersion",
+        )?;
 
         unsafe {
             let mut version = vec![0; 80];
@@ -6503,11 +9222,121 @@ impl<'nvml> Device<'nvml> {
         NvLink { device: self, link }
     }
 
+    /**
+    Gets the type of device at the far end of the given NvLink, e.g. whether
+    it is another GPU or an NvSwitch.
+
+    Shorthand for `.link_wrapper_for(link).remote_device_type(link)`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `link` or this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+    */
+    pub fn nvlink_remote_device_type(&self, link: u32) -> Result<IntDeviceType, NvmlError> {
+        self.link_wrapper_for(link).remote_device_type(link)
+    }
+
+    /**
+    Gets the error counters of every active NvLink on this `Device`.
+
+    Links up to `NVML_NVLINK_MAX_LINKS` are checked. Links that are inactive,
+    don't exist or return `NotSupported` are skipped, so a `Device` without
+    NvLink returns an empty `Vec`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `GpuLost`, if this `Device` has fallen off the bus or is otherwise inaccessible
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+    */
+    pub fn nvlink_error_summary(&self) -> Result<Vec<LinkErrors>, NvmlError> {
+        let link_errors = |link: u32| -> Result<Option<LinkErrors>, NvmlError> {
+            let nvlink = self.link_wrapper_for(link);
+
+            if !nvlink.is_active()? {
+                return Ok(None);
+            }
+
+            Ok(Some(LinkErrors {
+                link,
+                crc_flit: nvlink.error_counter(ErrorCounter::DlCrcFlit)?,
+                crc_data: nvlink.error_counter(ErrorCounter::DlCrcData)?,
+                replay: nvlink.error_counter(ErrorCounter::DlReplay)?,
+                recovery: nvlink.error_counter(ErrorCounter::DlRecovery)?,
+            }))
+        };
+
+        let mut summary = vec![];
+
+        for link in 0..NVML_NVLINK_MAX_LINKS {
+            match link_errors(link) {
+                Ok(Some(errors)) => summary.push(errors),
+                Ok(None) | Err(NvmlError::NotSupported) | Err(NvmlError::InvalidArg) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /**
+    Gets this `Device`'s NvLink fabric registration info, such as its cluster
+    UUID and clique ID.
+
+    On NvSwitch-based systems, check that `state` is
+    `GpuFabricState::Completed` and `status` is `Ok` to confirm that the fabric
+    manager has finished bringing this `Device` up.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Hopper and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetGpuFabricInfoV")]
+    pub fn gpu_fabric_info(&self) -> Result<GpuFabricInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetGpuFabricInfoV.as_ref(),
+            "nvmlDeviceGetGpuFabricInfoV",
+        )?;
+
+        unsafe {
+            let mut info: nvmlGpuFabricInfo_v2_t = mem::zeroed();
+
+            // Implements NVML_STRUCT_VERSION(GpuFabricInfo, 2), as detailed in nvml.h
+            info.version =
+                (mem::size_of::<nvmlGpuFabricInfo_v2_t>() | (2_usize << 24_usize)) as u32;
+
+            // The v2 struct is a prefix of `nvmlGpuFabricInfoV_t`; the version
+            // field tells NVML which layout we passed.
+            nvml_try(sym(
+                self.device,
+                &mut info as *mut nvmlGpuFabricInfo_v2_t as *mut nvmlGpuFabricInfoV_t,
+            ))?;
+
+            GpuFabricInfo::try_from(info)
+        }
+    }
+
     // vGPU
 
     /// Obtain a list of vGPU type (profiles) supported by the device, if any.
     pub fn vgpu_supported_types(&self) -> Result<Vec<VgpuType<'_>>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetSupportedVgpus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetSupportedVgpus.as_ref(),
+            "nvmlDeviceGetSupportedVgpus",
+        )?;
         let mut ids = vec![];
 
         unsafe {
@@ -6515,8 +9344,20 @@ impl<'nvml> Device<'nvml> {
 
             nvml_try_count(sym(self.device, &mut count, ids.as_mut_ptr()))?;
 
-            ids.resize(count as usize, 0);
-            nvml_try(sym(self.device, &mut count, ids.as_mut_ptr()))?;
+            loop {
+                ids.resize(count as usize, 0);
+
+                match sym(self.device, &mut count, ids.as_mut_ptr()) {
+                    // The number of types grew between calls; `count` is now
+                    // the required size, so try again.
+                    nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE => continue,
+                    value => nvml_try(value)?,
+                }
+
+                break;
+            }
+
+            ids.truncate(count as usize);
         }
 
         Ok(ids.into_iter().map(|id| VgpuType::new(self, id)).collect())
@@ -6524,7 +9365,10 @@ impl<'nvml> Device<'nvml> {
 
     /// Obtain a list of vGPU type (profiles) creatable on the device, if any.
     pub fn vgpu_creatable_types(&self) -> Result<Vec<VgpuType<'_>>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetCreatableVgpus.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetCreatableVgpus.as_ref(),
+            "nvmlDeviceGetCreatableVgpus",
+        )?;
         let mut ids = vec![];
 
         unsafe {
@@ -6532,8 +9376,20 @@ impl<'nvml> Device<'nvml> {
 
             nvml_try_count(sym(self.device, &mut count, ids.as_mut_ptr()))?;
 
-            ids.resize(count as usize, 0);
-            nvml_try(sym(self.device, &mut count, ids.as_mut_ptr()))?;
+            loop {
+                ids.resize(count as usize, 0);
+
+                match sym(self.device, &mut count, ids.as_mut_ptr()) {
+                    // The number of types grew between calls; `count` is now
+                    // the required size, so try again.
+                    nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE => continue,
+                    value => nvml_try(value)?,
+                }
+
+                break;
+            }
+
+            ids.truncate(count as usize);
         }
 
         Ok(ids.into_iter().map(|id| VgpuType::new(self, id)).collect())
@@ -6546,93 +9402,296 @@ impl<'nvml> Device<'nvml> {
                 .lib
                 .nvmlDeviceGetVgpuSchedulerCapabilities
                 .as_ref(),
+            "nvmlDeviceGetVgpuSchedulerCapabilities",
+        )?;
+
+        unsafe {
+            let mut capabilities: nvmlVgpuSchedulerCapabilities_t = mem::zeroed();
+
+            nvml_try(sym(self.device, &mut capabilities))?;
+
+            Ok(VgpuSchedulerCapabilities::from(capabilities))
+        }
+    }
+
+    /// Obtain the n log entries (max 200) of the vGPU scheduler, to be called several times if need
+    /// be.
+    pub fn vgpu_scheduler_log(&self) -> Result<VgpuSchedulerLog, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVgpuSchedulerLog.as_ref(),
+            "nvmlDeviceGetVgpuSchedulerLog",
+        )?;
+
+        unsafe {
+            let mut schedulerlog: nvmlVgpuSchedulerLog_t = mem::zeroed();
+
+            nvml_try(sym(self.device, &mut schedulerlog))?;
+
+            Ok(VgpuSchedulerLog::from(schedulerlog))
+        }
+    }
+
+    /// Obtain the vGPU scheduler state of the device
+    pub fn vgpu_scheduler_state(&self) -> Result<VgpuSchedulerGetState, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVgpuSchedulerState.as_ref(),
+            "nvmlDeviceGetVgpuSchedulerState",
+        )?;
+
+        unsafe {
+            let mut scheduler_state: nvmlVgpuSchedulerGetState_t = mem::zeroed();
+
+            nvml_try(sym(self.device, &mut scheduler_state))?;
+
+            Ok(VgpuSchedulerGetState::from(scheduler_state))
+        }
+    }
+
+    /// Set the vGPU scheduler state of the device
+    pub fn set_vgpu_scheduler_state(
+        &self,
+        scheduler_state: VgpuSchedulerSetState,
+    ) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetVgpuSchedulerState.as_ref(),
+            "nvmlDeviceSetVgpuSchedulerState",
+        )?;
+
+        unsafe { nvml_try(sym(self.device, &mut scheduler_state.as_c())) }
+    }
+
+    /**
+    Checks whether this `Device` is in SR-IOV host mode.
+
+    SR-IOV host mode is reported only if both this `Device` and the driver
+    support it; it changes how vGPU instances are created and enumerated.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetHostVgpuMode")]
+    pub fn vgpu_host_mode(&self) -> Result<HostVgpuMode, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetHostVgpuMode.as_ref(),
+            "nvmlDeviceGetHostVgpuMode",
+        )?;
+
+        unsafe {
+            let mut mode: nvmlHostVgpuMode_t = 0;
+
+            nvml_try(sym(self.device, &mut mode))?;
+
+            HostVgpuMode::try_from(mode)
+        }
+    }
+
+    /**
+    Queries the given vGPU capability of this `Device`.
+
+    A non-zero value means the capability is supported. For capabilities that
+    carry data (such as `VgpuCapability::ReadDeviceBufferBw`) the value is that
+    data; see `.vgpu_capability()` if you only need to know whether it's
+    supported.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` is not in vGPU mode
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Maxwell and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetVgpuCapabilities")]
+    pub fn vgpu_capabilities(&self, cap: VgpuCapability) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVgpuCapabilities.as_ref(),
+            "nvmlDeviceGetVgpuCapabilities",
+        )?;
+
+        unsafe {
+            let mut res: c_uint = 0;
+            nvml_try(sym(self.device, cap.as_c(), &mut res))?;
+
+            Ok(res)
+        }
+    }
+
+    /**
+    Checks whether this `Device` supports the given vGPU capability, such as
+    running fractional or heterogeneous timesliced vGPU profiles.
+
+    # Errors
+
+    Same as `.vgpu_capabilities()`.
+    */
+    pub fn vgpu_capability(&self, cap: VgpuCapability) -> Result<bool, NvmlError> {
+        Ok(self.vgpu_capabilities(cap)? != 0)
+    }
+
+    pub fn vgpu_set_capabilities(
+        &self,
+        cap: VgpuCapability,
+        enable: bool,
+    ) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetVgpuCapabilities.as_ref(),
+            "nvmlDeviceSetVgpuCapabilities",
+        )?;
+
+        unsafe {
+            let state: nvmlEnableState_t = match enable {
+                true => nvmlEnableState_enum_NVML_FEATURE_ENABLED,
+                false => nvmlEnableState_enum_NVML_FEATURE_DISABLED,
+            };
+
+            nvml_try(sym(self.device, cap.as_c(), state))
+        }
+    }
+
+    /**
+    Gets the vGPU metadata for this physical `Device`.
+
+    Pass the result, along with a vGPU instance's metadata, to
+    `Nvml.vgpu_compatibility()` to check whether that vGPU can be booted or
+    migrated onto this `Device`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if vGPU is not supported by the system
+    * `Utf8Error`, if the driver version string could not be interpreted as UTF-8
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetVgpuMetadata")]
+    pub fn vgpu_metadata(&self) -> Result<VgpuPgpuMetadata, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVgpuMetadata.as_ref(),
+            "nvmlDeviceGetVgpuMetadata",
         )?;
 
         unsafe {
-            let mut capabilities: nvmlVgpuSchedulerCapabilities_t = mem::zeroed();
+            let mut size: c_uint = 0;
 
-            nvml_try(sym(self.device, &mut capabilities))?;
+            nvml_try_count(sym(self.device, std::ptr::null_mut(), &mut size))?;
+            let mut buffer: Vec<u32> = vec![0; (size as usize + 3) / 4];
+            nvml_try(sym(
+                self.device,
+                buffer.as_mut_ptr() as *mut nvmlVgpuPgpuMetadata_t,
+                &mut size,
+            ))?;
 
-            Ok(VgpuSchedulerCapabilities::from(capabilities))
+            let raw = slice::from_raw_parts(buffer.as_ptr() as *const u8, size as usize);
+            VgpuPgpuMetadata::try_from_raw(raw.to_vec())
         }
     }
 
-    /// Obtain the n log entries (max 200) of the vGPU scheduler, to be called several times if need
-    /// be.
-    pub fn vgpu_scheduler_log(&self) -> Result<VgpuSchedulerLog, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetVgpuSchedulerLog.as_ref())?;
+    /**
+    Gets the GRID licensable features of this `Device`.
 
-        unsafe {
-            let mut schedulerlog: nvmlVgpuSchedulerLog_t = mem::zeroed();
+    # Errors
 
-            nvml_try(sym(self.device, &mut schedulerlog))?;
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Utf8Error`, if a string obtained from the C function is not valid UTF-8
+    * `Unknown`, on any unexpected error
 
-            Ok(VgpuSchedulerLog::from(schedulerlog))
-        }
-    }
+    # Device Support
 
-    /// Obtain the vGPU scheduler state of the device
-    pub fn vgpu_scheduler_state(&self) -> Result<VgpuSchedulerGetState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetVgpuSchedulerState.as_ref())?;
+    Supports Maxwell and newer fully supported devices.
+    */
+    #[doc(alias = "nvmlDeviceGetGridLicensableFeatures_v4")]
+    pub fn grid_licensable_features(&self) -> Result<GridLicensableFeatures, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml
+                .lib
+                .nvmlDeviceGetGridLicensableFeatures_v4
+                .as_ref(),
+            "nvmlDeviceGetGridLicensableFeatures_v4",
+        )?;
 
         unsafe {
-            let mut scheduler_state: nvmlVgpuSchedulerGetState_t = mem::zeroed();
+            let mut features: nvmlGridLicensableFeatures_t = mem::zeroed();
+            nvml_try(sym(self.device, &mut features))?;
 
-            nvml_try(sym(self.device, &mut scheduler_state))?;
-
-            Ok(VgpuSchedulerGetState::from(scheduler_state))
+            GridLicensableFeatures::try_from(features)
         }
     }
 
-    /// Set the vGPU scheduler state of the device
-    pub fn set_vgpu_scheduler_state(
-        &self,
-        scheduler_state: VgpuSchedulerSetState,
-    ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetVgpuSchedulerState.as_ref())?;
+    /**
+    Checks whether vGPU heterogeneous mode is enabled for this `Device`.
 
-        unsafe { nvml_try(sym(self.device, &mut scheduler_state.as_c())) }
-    }
+    When in heterogeneous mode, a GPU can concurrently host timesliced vGPUs
+    with differing framebuffer sizes.
 
-    /// Check if the GPU is on vGPU host mode
-    pub fn vgpu_host_mode(&self) -> Result<HostVgpuMode, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetHostVgpuMode.as_ref())?;
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `NotSupported`, if MIG is enabled or this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetVgpuHeterogeneousMode")]
+    pub fn vgpu_heterogeneous_mode(&self) -> Result<bool, NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceGetVgpuHeterogeneousMode.as_ref(),
+            "nvmlDeviceGetVgpuHeterogeneousMode",
+        )?;
 
         unsafe {
-            let mut mode: nvmlHostVgpuMode_t = 0;
+            let mut mode: nvmlVgpuHeterogeneousMode_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(VgpuHeterogeneousMode, 1), as detailed in nvml.h
+            mode.version = (std::mem::size_of::<nvmlVgpuHeterogeneousMode_v1_t>()
+                | (1_usize << 24_usize)) as u32;
 
             nvml_try(sym(self.device, &mut mode))?;
 
-            HostVgpuMode::try_from(mode)
+            bool_from_state(mode.mode)
         }
     }
 
-    /// Query the given vGPU capability
-    pub fn vgpu_capabilities(&self, cap: VgpuCapability) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceGetVgpuCapabilities.as_ref())?;
+    /**
+    Enables or disables vGPU heterogeneous mode for this `Device`.
 
-        unsafe {
-            let mut res: c_uint = 0;
-            nvml_try(sym(self.device, cap.as_c(), &mut res))?;
+    Setting the mode fails with `InUse` if any vGPU instance is active on this
+    `Device` (or, on KVM, if any MDEV device has been created on it); shut down
+    the vGPU VMs and retry.
 
-            Ok(res)
-        }
-    }
+    # Errors
 
-    pub fn vgpu_set_capabilities(
-        &self,
-        cap: VgpuCapability,
-        enable: bool,
-    ) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlDeviceSetVgpuCapabilities.as_ref())?;
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `Device` is invalid
+    * `InUse`, if this `Device` is in use
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `NotSupported`, if MIG is enabled or this `Device` doesn't support this feature
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceSetVgpuHeterogeneousMode")]
+    pub fn set_vgpu_heterogeneous_mode(&mut self, enabled: bool) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlDeviceSetVgpuHeterogeneousMode.as_ref(),
+            "nvmlDeviceSetVgpuHeterogeneousMode",
+        )?;
 
         unsafe {
-            let state: nvmlEnableState_t = match enable {
-                true => nvmlEnableState_enum_NVML_FEATURE_ENABLED,
-                false => nvmlEnableState_enum_NVML_FEATURE_DISABLED,
-            };
+            let mut mode: nvmlVgpuHeterogeneousMode_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(VgpuHeterogeneousMode, 1), as detailed in nvml.h
+            mode.version = (std::mem::size_of::<nvmlVgpuHeterogeneousMode_v1_t>()
+                | (1_usize << 24_usize)) as u32;
+            mode.mode = state_from_bool(enabled);
 
-            nvml_try(sym(self.device, cap.as_c(), state))
+            nvml_try(sym(self.device, &mode))
         }
     }
 
@@ -6653,7 +9712,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlGpmQueryDeviceSupport")]
     pub fn gpm_support(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlGpmQueryDeviceSupport.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlGpmQueryDeviceSupport.as_ref(),
+            "nvmlGpmQueryDeviceSupport",
+        )?;
 
         unsafe {
             let mut support: nvmlGpmSupport_t = mem::zeroed();
@@ -6681,7 +9743,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlGpmQueryIfStreamingEnabled")]
     pub fn gpm_streaming_enabled(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlGpmQueryIfStreamingEnabled.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlGpmQueryIfStreamingEnabled.as_ref(),
+            "nvmlGpmQueryIfStreamingEnabled",
+        )?;
 
         unsafe {
             let mut state: c_uint = 0;
@@ -6707,7 +9772,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlGpmSetStreamingEnabled")]
     pub fn set_gpm_streaming_enabled(&self, enabled: bool) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlGpmSetStreamingEnabled.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlGpmSetStreamingEnabled.as_ref(),
+            "nvmlGpmSetStreamingEnabled",
+        )?;
 
         let state: c_uint = if enabled { 1 } else { 0 };
 
@@ -6734,7 +9802,7 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlGpmSampleGet")]
     pub fn gpm_sample(&self) -> Result<GpmSample<'nvml>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlGpmSampleGet.as_ref())?;
+        let sym = nvml_sym(self.nvml.lib.nvmlGpmSampleGet.as_ref(), "nvmlGpmSampleGet")?;
 
         let sample = GpmSample::alloc(self.nvml)?;
 
@@ -6766,7 +9834,10 @@ impl<'nvml> Device<'nvml> {
     */
     #[doc(alias = "nvmlGpmMigSampleGet")]
     pub fn gpm_mig_sample(&self, gpu_instance_id: u32) -> Result<GpmSample<'nvml>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlGpmMigSampleGet.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlGpmMigSampleGet.as_ref(),
+            "nvmlGpmMigSampleGet",
+        )?;
 
         let sample = GpmSample::alloc(self.nvml)?;
 
@@ -6778,6 +9849,93 @@ impl<'nvml> Device<'nvml> {
     }
 }
 
+// Maps `NotSupported` to `None` for readings that are optional on some devices.
+fn ok_if_supported<T>(result: Result<T, NvmlError>) -> Result<Option<T>, NvmlError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(NvmlError::NotSupported) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Reads the NUL-terminated string written by a getter that fills a
+// caller-provided buffer of `len` bytes.
+unsafe fn query_c_string(
+    device: nvmlDevice_t,
+    sym: unsafe extern "C" fn(nvmlDevice_t, *mut c_char, c_uint) -> nvmlReturn_t,
+    len: c_uint,
+) -> Result<CString, NvmlError> {
+    let mut buf = vec![0; len as usize];
+    nvml_try(sym(device, buf.as_mut_ptr(), len))?;
+
+    Ok(CStr::from_ptr(buf.as_ptr()).to_owned())
+}
+
+// Integer reading of a sample value, clamping negative values to 0.
+fn sample_value_as_u64(value: &SampleValue) -> u64 {
+    match *value {
+        SampleValue::F64(v) => v as u64,
+        SampleValue::U32(v) => v as u64,
+        SampleValue::U64(v) => v,
+        SampleValue::I64(v) => v.max(0) as u64,
+        SampleValue::I32(v) => v.max(0) as u64,
+        SampleValue::U16(v) => v as u64,
+    }
+}
+
+// Exact integer reading of a sample value, or `None` for floating-point values.
+fn sample_value_as_i128(value: &SampleValue) -> Option<i128> {
+    match *value {
+        SampleValue::F64(_) => None,
+        SampleValue::U32(v) => Some(v.into()),
+        SampleValue::U64(v) => Some(v.into()),
+        SampleValue::I64(v) => Some(v.into()),
+        SampleValue::I32(v) => Some(v.into()),
+        SampleValue::U16(v) => Some(v.into()),
+    }
+}
+
+fn sample_value_as_f64(value: &SampleValue) -> f64 {
+    match *value {
+        SampleValue::F64(v) => v,
+        SampleValue::U32(v) => v.into(),
+        SampleValue::U64(v) => v as f64,
+        SampleValue::I64(v) => v as f64,
+        SampleValue::I32(v) => v.into(),
+        SampleValue::U16(v) => v.into(),
+    }
+}
+
+// Per-second rate of change between two samples with μs timestamps. Integer
+// values are subtracted exactly before being converted to `f64`.
+fn sample_value_rate(
+    prev: &SampleValue,
+    prev_timestamp: i64,
+    now: &SampleValue,
+    now_timestamp: i64,
+) -> Result<f64, NvmlError> {
+    let elapsed_us = now_timestamp - prev_timestamp;
+    if elapsed_us <= 0 {
+        return Err(NvmlError::InvalidArg);
+    }
+
+    let delta = match (sample_value_as_i128(now), sample_value_as_i128(prev)) {
+        (Some(now), Some(prev)) => (now - prev) as f64,
+        _ => sample_value_as_f64(now) - sample_value_as_f64(prev),
+    };
+
+    Ok(delta / elapsed_us as f64 * 1_000_000.0)
+}
+
+// Rounded mean of the given readings, or `None` if there are none.
+fn mean(values: &[u64]) -> Option<u32> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let count = values.len() as u64;
+    Some(((values.iter().sum::<u64>() + count / 2) / count) as u32)
+}
 #[cfg(test)]
 #[deny(unused_mut)]
 mod test {
@@ -6786,11 +9944,17 @@ mod test {
     #[cfg(target_os = "windows")]
     use crate::bitmasks::Behavior;
     use crate::enum_wrappers::device::*;
-    use crate::enums::device::GpuLockedClocksSetting;
+    use crate::enums::device::{BusIdFormat, GpuLockedClocksSetting};
     use crate::error::*;
-    use crate::structs::device::FieldId;
+    #[cfg(target_os = "linux")]
+    use crate::ffi::bindings::{
+        NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED, NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+        NVML_GPU_INSTANCE_PROFILE_1_SLICE,
+    };
+    use crate::structs::device::{ConfidentialComputeKeyRotationThreshold, FieldId};
     use crate::sys_exports::field_id::*;
     use crate::test_utils::*;
+    use std::time::Duration;
 
     // This modifies device state, so we don't want to actually run the test
     #[allow(dead_code)]
@@ -6855,6 +10019,18 @@ mod test {
         test_with_device(3, &nvml, |device| device.board_id())
     }
 
+    #[test]
+    fn module_id() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.module_id())
+    }
+
+    #[test]
+    fn c2c_mode_info() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.c2c_mode_info())
+    }
+
     #[test]
     fn numa_node_id() {
         let nvml = nvml();
@@ -6905,6 +10081,37 @@ mod test {
         test_with_device(3, &nvml, |device| device.compute_mode())
     }
 
+    #[test]
+    fn all_clocks() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.all_clocks())
+    }
+
+    #[test]
+    fn adaptive_clocking_enabled() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.adaptive_clocking_enabled())
+    }
+
+    #[test]
+    fn gpu_fabric_info() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.gpu_fabric_info())
+    }
+
+    #[test]
+    #[ignore = "my machine does not support this call"]
+    fn nvlink_remote_device_type() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.nvlink_remote_device_type(0))
+    }
+
+    #[test]
+    fn nvlink_error_summary() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.nvlink_error_summary())
+    }
+
     #[test]
     fn clock_info() {
         let nvml = nvml();
@@ -6921,12 +10128,24 @@ mod test {
         })
     }
 
+    #[test]
+    fn video_clock() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.video_clock())
+    }
+
     #[test]
     fn running_compute_processes() {
         let nvml = nvml();
         test_with_device(3, &nvml, |device| device.running_compute_processes())
     }
 
+    #[test]
+    fn running_compute_processes_count() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.running_compute_processes_count())
+    }
+
     #[cfg(feature = "legacy-functions")]
     #[cfg_attr(feature = "legacy-functions", test)]
     fn running_compute_processes_v2() {
@@ -6972,6 +10191,18 @@ mod test {
         test_with_device(3, &nvml, |device| device.decoder_utilization())
     }
 
+    #[test]
+    fn jpg_utilization() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.jpg_utilization())
+    }
+
+    #[test]
+    fn ofa_utilization() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.ofa_utilization())
+    }
+
     #[test]
     #[ignore = "my machine does not support this call"]
     fn default_applications_clock() {
@@ -7008,6 +10239,59 @@ mod test {
         test_with_device(3, &nvml, |device| device.driver_model())
     }
 
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn driver_model_transition() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.driver_model_transition())
+    }
+
+    #[test]
+    fn confidential_compute_state() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.confidential_compute_state())
+    }
+
+    #[test]
+    fn is_confidential_compute_ready() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.is_confidential_compute_ready())
+    }
+
+    #[test]
+    fn confidential_compute_key_rotation_threshold() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            device.confidential_compute_key_rotation_threshold()
+        })
+    }
+
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn set_confidential_compute_key_rotation_threshold() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device
+            .set_confidential_compute_key_rotation_threshold(
+                ConfidentialComputeKeyRotationThreshold {
+                    max_attacker_advantage: 60,
+                },
+            )
+            .expect("set to default")
+    }
+
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn set_confidential_compute_unprotected_mem_size() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device
+            .set_confidential_compute_unprotected_mem_size(0)
+            .expect("set to zero")
+    }
+
     #[test]
     #[ignore = "my machine does not support this call"]
     fn is_ecc_enabled() {
@@ -7103,6 +10387,12 @@ mod test {
         test_with_device(3, &nvml, |device| device.running_graphics_processes_v2())
     }
 
+    #[test]
+    fn process_memory_map() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.process_memory_map())
+    }
+
     #[test]
     fn process_utilization_stats() {
         let nvml = nvml();
@@ -7156,6 +10446,12 @@ mod test {
         })
     }
 
+    #[test]
+    fn max_pcie_link_gen_device() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.max_pcie_link_gen_device())
+    }
+
     #[test]
     fn max_pcie_link_gen() {
         let nvml = nvml();
@@ -7187,6 +10483,26 @@ mod test {
         test_with_device(3, &nvml, |device| device.memory_info())
     }
 
+    #[test]
+    fn memory_info_accounts_for_reserved() {
+        let nvml = nvml();
+        let info = device(&nvml).memory_info().expect("memory info");
+
+        assert!(info.used + info.reserved + info.free <= info.total);
+    }
+
+    #[test]
+    fn reserved_memory() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.reserved_memory())
+    }
+
+    #[test]
+    fn memory_report() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.memory_report())
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn minor_number() {
@@ -7195,23 +10511,95 @@ mod test {
     }
 
     #[test]
-    fn is_multi_gpu_board() {
+    fn is_multi_gpu_board() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.is_multi_gpu_board())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn possible_placements() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.possible_placements(0))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn profile_info() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.profile_info(0))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn gpu_instances() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            let profile = device.profile_info(NVML_GPU_INSTANCE_PROFILE_1_SLICE)?;
+
+            device
+                .gpu_instances(&profile)
+                .map(|instances| instances.len())
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn compute_instance_remaining_by_profile() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            let profile = device.profile_info(NVML_GPU_INSTANCE_PROFILE_1_SLICE)?;
+            let gpu_instances = device.gpu_instances(&profile)?.len();
+
+            let remaining = device.compute_instance_remaining_by_profile(
+                NVML_GPU_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED,
+            )?;
+
+            // No GPU instance with the profile must be reported as `None`,
+            // not as a capacity of 0
+            assert_eq!(remaining.is_none(), gpu_instances == 0);
+
+            Ok(remaining)
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn gpu_instance_remaining_capacity() {
         let nvml = nvml();
-        test_with_device(3, &nvml, |device| device.is_multi_gpu_board())
+        test_with_device(3, &nvml, |device| {
+            let profile = device.profile_info(NVML_GPU_INSTANCE_PROFILE_1_SLICE)?;
+
+            device.gpu_instance_remaining_capacity(profile.id)
+        })
     }
 
     #[cfg(target_os = "linux")]
     #[test]
-    fn possible_placements() {
+    fn gpu_instance_by_id() {
         let nvml = nvml();
-        test_with_device(3, &nvml, |device| device.possible_placements(0))
+        test_with_device(3, &nvml, |device| {
+            device.gpu_instance_by_id(0).and_then(|gi| gi.info())
+        })
     }
 
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
     #[cfg(target_os = "linux")]
-    #[test]
-    fn profile_info() {
+    fn create_gpu_instance() {
         let nvml = nvml();
-        test_with_device(3, &nvml, |device| device.profile_info(0))
+        let device = device(&nvml);
+        let profile = device
+            .profile_info(NVML_GPU_INSTANCE_PROFILE_1_SLICE)
+            .expect("gpu instance profile info");
+
+        device
+            .create_gpu_instance(profile.id)
+            .expect("created gpu instance")
+            .destroy()
+            .expect("destroyed gpu instance")
     }
 
     #[test]
@@ -7220,6 +10608,12 @@ mod test {
         test_with_device(3, &nvml, |device| device.mig_mode())
     }
 
+    #[test]
+    fn is_mig_enabled() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.is_mig_enabled())
+    }
+
     #[test]
     fn set_mig_mode() {
         let nvml = nvml();
@@ -7233,6 +10627,23 @@ mod test {
         test(3, || device.mig_device_by_index(0))
     }
 
+    #[test]
+    #[ignore = "requires MIG to be enabled"]
+    fn mig_device_queries() {
+        let nvml = nvml();
+        let parent = device(&nvml);
+        let mig = parent.mig_device_by_index(0).expect("mig device");
+
+        test(3, || mig.uuid());
+        test(3, || mig.bar1_memory_info());
+        test(3, || mig.utilization_rates());
+        test(3, || mig.running_compute_processes());
+
+        let parent_total = parent.memory_info().expect("parent memory").total;
+        let mig_total = mig.memory_info().expect("mig memory").total;
+        assert!(mig_total < parent_total);
+    }
+
     #[test]
     fn mig_device_count() {
         let nvml = nvml();
@@ -7259,12 +10670,57 @@ mod test {
         test_with_device(3, &nvml, |device| device.mig_device_count())
     }
 
+    #[test]
+    fn name_lossy() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.name_lossy())
+    }
+
+    #[test]
+    fn recovery_action() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.recovery_action())
+    }
+
+    #[test]
+    fn nvlink_throughput() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.nvlink_throughput())
+    }
+
+    #[test]
+    fn pcie_utilization() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.pcie_utilization())
+    }
+
+    #[test]
+    fn sm_arch_string() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.sm_arch_string())
+    }
+
+    #[test]
+    fn pci_bus_id() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            device.pci_bus_id(BusIdFormat::Legacy)?;
+            device.pci_bus_id(BusIdFormat::Extended)
+        })
+    }
+
     #[test]
     fn pci_info() {
         let nvml = nvml();
         test_with_device(3, &nvml, |device| device.pci_info())
     }
 
+    #[test]
+    fn pci_info_ext() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.pci_info_ext())
+    }
+
     #[test]
     fn pcie_replay_counter() {
         let nvml = nvml();
@@ -7286,6 +10742,18 @@ mod test {
         test_with_device(3, &nvml, |device| device.performance_state())
     }
 
+    #[test]
+    fn health_snapshot() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.health_snapshot())
+    }
+
+    #[test]
+    fn performance_snapshot() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.performance_snapshot())
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn is_in_persistent_mode() {
@@ -7299,6 +10767,12 @@ mod test {
         test_with_device(3, &nvml, |device| device.power_management_limit_default())
     }
 
+    #[test]
+    fn dynamic_pstates_info() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.dynamic_pstates_info())
+    }
+
     #[test]
     fn power_management_limit() {
         let nvml = nvml();
@@ -7313,6 +10787,14 @@ mod test {
         });
     }
 
+    #[test]
+    fn clock_offset_ranges() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            device.clock_offset_ranges(Clock::Graphics)
+        });
+    }
+
     #[test]
     fn supported_performance_states() {
         let nvml = nvml();
@@ -7374,6 +10856,18 @@ mod test {
         test_with_device(3, &nvml, |device| device.are_pages_pending_retired())
     }
 
+    #[test]
+    fn remapped_rows() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.remapped_rows())
+    }
+
+    #[test]
+    fn needs_replacement() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.needs_replacement())
+    }
+
     #[test]
     #[ignore = "my machine does not support this call"]
     fn samples() {
@@ -7443,6 +10937,51 @@ mod test {
         })
     }
 
+    #[test]
+    fn field_value_rate() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            let prev = device
+                .field_values_for(&[FieldId(NVML_FI_DEV_PCIE_COUNT_TX_BYTES)])?
+                .remove(0)?;
+
+            device.field_value_rate(FieldId(NVML_FI_DEV_PCIE_COUNT_TX_BYTES), &prev)
+        })
+    }
+
+    #[test]
+    fn sample_value_rate() {
+        use super::sample_value_rate;
+        use crate::enums::device::SampleValue;
+
+        // 1000 bytes over 500ms
+        let rate = sample_value_rate(
+            &SampleValue::U64(u64::MAX - 1000),
+            1_000_000,
+            &SampleValue::U64(u64::MAX),
+            1_500_000,
+        )
+        .unwrap();
+        assert_eq!(rate, 2000.0);
+
+        let rate =
+            sample_value_rate(&SampleValue::I64(-10), 0, &SampleValue::U32(10), 2_000_000).unwrap();
+        assert_eq!(rate, 10.0);
+
+        let rate = sample_value_rate(&SampleValue::F64(1.5), 0, &SampleValue::F64(0.5), 1_000_000)
+            .unwrap();
+        assert_eq!(rate, -1.0);
+
+        let rate =
+            sample_value_rate(&SampleValue::U32(1), 0, &SampleValue::F64(3.0), 4_000_000).unwrap();
+        assert_eq!(rate, 0.5);
+
+        assert!(matches!(
+            sample_value_rate(&SampleValue::U64(0), 10, &SampleValue::U64(1), 10),
+            Err(NvmlError::InvalidArg)
+        ));
+    }
+
     /// Verify that the v12↔v13U1 field ID remapping works correctly at runtime.
     ///
     /// On a v13U1+ driver (>= 580.82), CLOCKS_EVENT_REASON fields must be
@@ -7597,6 +11136,13 @@ mod test {
         test_with_device(3, &nvml, |device| device.serial())
     }
 
+    #[test]
+    #[ignore = "my machine does not support this call"]
+    fn serial_lossy() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.serial_lossy())
+    }
+
     #[test]
     #[ignore = "my machine does not support this call"]
     fn board_part_number() {
@@ -7653,6 +11199,15 @@ mod test {
         })
     }
 
+    #[test]
+    #[ignore = "my machine does not support this call"]
+    fn is_applications_clock_pair_supported() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            device.is_applications_clock_pair_supported(810, 810)
+        })
+    }
+
     #[test]
     fn temperature() {
         let nvml = nvml();
@@ -7661,6 +11216,18 @@ mod test {
         })
     }
 
+    #[test]
+    fn thermal_settings() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.thermal_settings())
+    }
+
+    #[test]
+    fn temperatures() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.temperatures())
+    }
+
     #[test]
     fn temperature_threshold() {
         let nvml = nvml();
@@ -7718,6 +11285,38 @@ mod test {
         test_with_device(3, &nvml, |device| device.uuid())
     }
 
+    #[test]
+    fn uuid_lossy() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.uuid_lossy())
+    }
+
+    #[test]
+    fn cached_uuid() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.cached_uuid())
+    }
+
+    #[test]
+    fn display() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| Ok(device.to_string()))
+    }
+
+    #[test]
+    fn power_samples_since() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.power_samples_since(None))
+    }
+
+    #[test]
+    fn average_utilization() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            device.average_utilization(Duration::from_millis(200))
+        })
+    }
+
     #[test]
     fn utilization_rates() {
         let nvml = nvml();
@@ -7730,6 +11329,12 @@ mod test {
         test_with_device(3, &nvml, |device| device.vbios_version())
     }
 
+    #[test]
+    fn vbios_version_lossy() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vbios_version_lossy())
+    }
+
     #[test]
     fn violation_status() {
         let nvml = nvml();
@@ -7738,6 +11343,12 @@ mod test {
         })
     }
 
+    #[test]
+    fn throttling_report() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.throttling_report())
+    }
+
     #[test]
     fn num_cores() {
         let nvml = nvml();
@@ -7899,6 +11510,15 @@ mod test {
             .expect("set to true")
     }
 
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn clear_all_ecc_counts() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device.clear_all_ecc_counts().expect("cleared")
+    }
+
     // This modifies device state, so we don't want to actually run the test
     #[allow(dead_code)]
     fn set_api_restricted() {
@@ -7985,6 +11605,15 @@ mod test {
         device.reset_mem_locked_clocks().expect("clocks reset")
     }
 
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn reset_to_defaults() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device.reset_to_defaults().expect("reset to defaults")
+    }
+
     // This modifies device state, so we don't want to actually run the test
     #[allow(dead_code)]
     fn set_ecc() {
@@ -8085,6 +11714,13 @@ mod test {
         test_with_device(3, &nvml, |device| device.performance_modes())
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn current_performance_mode() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.current_performance_mode())
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn active_vgpus() {
@@ -8098,12 +11734,118 @@ mod test {
         test_with_device(3, &nvml, |device| device.vgpu_accounting_pids(0))
     }
 
+    #[test]
+    fn is_vgpu_accounting_enabled() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.is_vgpu_accounting_enabled(0))
+    }
+
     #[test]
     fn vgpu_accounting_instance() {
         let nvml = nvml();
         test_with_device(3, &nvml, |device| device.vgpu_accounting_instance(0, 0))
     }
 
+    #[test]
+    fn vgpu_license_info() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_license_info(0))
+    }
+
+    #[test]
+    fn vgpu_fbc_stats() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_fbc_stats(0))
+    }
+
+    #[test]
+    fn vgpu_fbc_sessions() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_fbc_sessions(0))
+    }
+
+    #[test]
+    fn vgpu_encoder_sessions() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_encoder_sessions(0))
+    }
+
+    #[test]
+    fn vgpu_encoder_capacity() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_encoder_capacity(0))
+    }
+
+    #[test]
+    fn vgpu_instances_utilization() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_instances_utilization(None))
+    }
+
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn set_vgpu_encoder_capacity() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device
+            .set_vgpu_encoder_capacity(0, 50)
+            .expect("set encoder capacity")
+    }
+
+    #[test]
+    fn grid_licensable_features() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.grid_licensable_features())
+    }
+
+    #[test]
+    fn vgpu_instance_metadata() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_instance_metadata(0))
+    }
+
+    #[test]
+    fn vgpu_supported_types() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            let types = device.vgpu_supported_types()?;
+            Ok(types.iter().map(|t| t.id()).collect::<Vec<_>>())
+        })
+    }
+
+    #[test]
+    fn vgpu_creatable_types() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            let types = device.vgpu_creatable_types()?;
+            Ok(types.iter().map(|t| t.id()).collect::<Vec<_>>())
+        })
+    }
+
+    #[test]
+    fn vgpu_heterogeneous_mode() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_heterogeneous_mode())
+    }
+
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn set_vgpu_heterogeneous_mode() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device
+            .set_vgpu_heterogeneous_mode(false)
+            .expect("set to false")
+    }
+
+    #[test]
+    fn vgpu_metadata() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_metadata())
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn virtualization_mode() {
@@ -8111,6 +11853,33 @@ mod test {
         test_with_device(3, &nvml, |device| device.virtualization_mode())
     }
 
+    #[test]
+    fn vgpu_capability() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            device.vgpu_capabilities(VgpuCapability::ReadDeviceBufferBw)?;
+            device.vgpu_capability(VgpuCapability::FractionalMultiVgpu)
+        })
+    }
+
+    #[test]
+    fn vgpu_host_mode() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| device.vgpu_host_mode())
+    }
+
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    #[cfg(target_os = "linux")]
+    fn set_virtualization_mode() {
+        let nvml = nvml();
+        let mut device = device(&nvml);
+
+        device
+            .set_virtualization_mode(GpuVirtualizationMode::HostVgpu)
+            .expect("set to host vgpu")
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn device_attributes() {
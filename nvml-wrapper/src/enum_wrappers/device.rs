@@ -2,6 +2,7 @@ use crate::error::NvmlError;
 use crate::ffi::bindings::*;
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
+use std::fmt::Display;
 use wrapcenum_derive::EnumWrapper;
 
 /// API types that allow changes to default permission restrictions.
@@ -131,6 +132,30 @@ pub enum Brand {
     TitanRTX,
 }
 
+impl Display for Brand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("Unknown"),
+            Self::Quadro => f.write_str("Quadro"),
+            Self::Tesla => f.write_str("Tesla"),
+            Self::NVS => f.write_str("NVS"),
+            Self::GRID => f.write_str("GRID"),
+            Self::GeForce => f.write_str("GeForce"),
+            Self::Titan => f.write_str("Titan"),
+            Self::VApps => f.write_str("NVIDIA Virtual Applications"),
+            Self::VPC => f.write_str("NVIDIA Virtual PC"),
+            Self::VCS => f.write_str("NVIDIA Virtual Compute Server"),
+            Self::VWS => f.write_str("NVIDIA RTX Virtual Workstation"),
+            Self::CloudGaming | Self::VGaming => f.write_str("NVIDIA Cloud Gaming"),
+            Self::QuadroRTX => f.write_str("Quadro RTX"),
+            Self::NvidiaRTX => f.write_str("NVIDIA RTX"),
+            Self::Nvidia => f.write_str("NVIDIA"),
+            Self::GeForceRTX => f.write_str("GeForce RTX"),
+            Self::TitanRTX => f.write_str("Titan RTX"),
+        }
+    }
+}
+
 /**
 Represents type of a bridge chip.
 
@@ -294,6 +319,26 @@ pub enum PcieUtilCounter {
     Receive,
 }
 
+/// GPU utilization domains reported by `Device.dynamic_pstates_info()`.
+// Checked against local
+#[derive(EnumWrapper, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[wrap(c_enum = "nvmlGpuUtilizationDomainId_t")]
+pub enum PstateDomain {
+    /// Graphics engine domain.
+    #[wrap(c_variant = "NVML_GPU_UTILIZATION_DOMAIN_GPU")]
+    Gpu,
+    /// Frame buffer domain.
+    #[wrap(c_variant = "NVML_GPU_UTILIZATION_DOMAIN_FB")]
+    FrameBuffer,
+    /// Video engine domain.
+    #[wrap(c_variant = "NVML_GPU_UTILIZATION_DOMAIN_VID")]
+    Video,
+    /// Bus interface domain.
+    #[wrap(c_variant = "NVML_GPU_UTILIZATION_DOMAIN_BUS")]
+    Bus,
+}
+
 /**
 Allowed performance states.
 
@@ -348,6 +393,15 @@ pub enum PerformanceState {
     Unknown,
 }
 
+impl Display for PerformanceState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("Unknown"),
+            other => write!(f, "P{}", other.as_c()),
+        }
+    }
+}
+
 /// Causes for page retirement.
 // Checked against local
 #[derive(EnumWrapper, Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -434,6 +488,21 @@ pub enum TemperatureThreshold {
     GpsCurr,
 }
 
+impl Display for TemperatureThreshold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shutdown => f.write_str("Shutdown"),
+            Self::Slowdown => f.write_str("Slowdown"),
+            Self::MemoryMax => f.write_str("Memory max"),
+            Self::GpuMax => f.write_str("GPU max"),
+            Self::AcousticMin => f.write_str("Acoustic min"),
+            Self::AcousticCurr => f.write_str("Acoustic current"),
+            Self::AcousticMax => f.write_str("Acoustic max"),
+            Self::GpsCurr => f.write_str("GPS current"),
+        }
+    }
+}
+
 /// Level relationships within a system between two GPUs.
 // Checked against local
 #[derive(EnumWrapper, Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -575,6 +644,10 @@ pub enum SampleValueType {
     UnsignedLongLong,
     #[wrap(c_variant = "NVML_VALUE_TYPE_SIGNED_LONG_LONG")]
     SignedLongLong,
+    #[wrap(c_variant = "NVML_VALUE_TYPE_SIGNED_INT")]
+    SignedInt,
+    #[wrap(c_variant = "NVML_VALUE_TYPE_UNSIGNED_SHORT")]
+    UnsignedShort,
 }
 
 /// Represents encoder types that capacity can be queried for.
@@ -697,3 +770,48 @@ pub enum VgpuCapability {
     #[wrap(c_variant = "NVML_DEVICE_VGPU_CAP_WRITE_DEVICE_BUFFER_BW")]
     WriteDeviceBufferBw,
 }
+
+/// GRID license feature codes.
+// Checked against local
+#[derive(EnumWrapper, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[wrap(c_enum = "nvmlGridLicenseFeatureCode_t")]
+pub enum GridLicenseFeatureCode {
+    #[wrap(c_variant = "NVML_GRID_LICENSE_FEATURE_CODE_UNKNOWN")]
+    Unknown,
+    /// Virtual GPU.
+    #[wrap(c_variant = "NVML_GRID_LICENSE_FEATURE_CODE_VGPU")]
+    Vgpu,
+    /// NVIDIA RTX (also known as Quadro Virtual Workstation).
+    #[wrap(c_variant = "NVML_GRID_LICENSE_FEATURE_CODE_NVIDIA_RTX")]
+    NvidiaRtx,
+    /// Gaming.
+    #[wrap(c_variant = "NVML_GRID_LICENSE_FEATURE_CODE_GAMING")]
+    Gaming,
+    /// Compute.
+    #[wrap(c_variant = "NVML_GRID_LICENSE_FEATURE_CODE_COMPUTE")]
+    Compute,
+}
+
+/// The action required to recover a `Device` after an error.
+// Checked against local
+#[derive(EnumWrapper, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[wrap(c_enum = "nvmlDeviceGpuRecoveryAction_s")]
+pub enum RecoveryAction {
+    /// No recovery action is needed.
+    #[wrap(c_variant = "NVML_GPU_RECOVERY_ACTION_NONE")]
+    None,
+    /// The `Device` must be reset.
+    #[wrap(c_variant = "NVML_GPU_RECOVERY_ACTION_GPU_RESET")]
+    GpuReset,
+    /// The node must be rebooted.
+    #[wrap(c_variant = "NVML_GPU_RECOVERY_ACTION_NODE_REBOOT")]
+    NodeReboot,
+    /// Peer-to-peer traffic must be drained; a reset is not required.
+    #[wrap(c_variant = "NVML_GPU_RECOVERY_ACTION_DRAIN_P2P")]
+    DrainP2p,
+    /// Peer-to-peer traffic must be drained and the `Device` reset.
+    #[wrap(c_variant = "NVML_GPU_RECOVERY_ACTION_DRAIN_AND_RESET")]
+    DrainAndReset,
+}
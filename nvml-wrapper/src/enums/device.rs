@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 use std::fmt::Display;
 use std::os::raw::c_uint;
 
+#[cfg(target_os = "windows")]
+use crate::enum_wrappers::device::DriverModel;
 use crate::enum_wrappers::device::{ClockLimitId, SampleValueType};
 use crate::error::NvmlError;
 use crate::ffi::bindings::*;
@@ -49,7 +51,13 @@ impl From<u64> for UsedGpuMemory {
     }
 }
 
-/// Represents different types of sample values.
+/**
+Represents different types of sample values.
+
+This is the decoded form of NVML's `nvmlValue_t` union, shared by samples and
+field values. Use `SampleValue::from_tag_and_union()` to decode a union given
+its `nvmlValueType_t` tag rather than reading the union directly.
+*/
 // Checked against local
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -58,9 +66,13 @@ pub enum SampleValue {
     U32(u32),
     U64(u64),
     I64(i64),
+    I32(i32),
+    U16(u16),
 }
 
 impl SampleValue {
+    /// Decodes `union` according to `tag`, which must be the type NVML
+    /// reported alongside it.
     pub fn from_tag_and_union(tag: &SampleValueType, union: nvmlValue_t) -> Self {
         use self::SampleValueType::*;
 
@@ -74,6 +86,8 @@ impl SampleValue {
                 UnsignedLong => SampleValue::U32(union.ulVal as u32),
                 UnsignedLongLong => SampleValue::U64(union.ullVal),
                 SignedLongLong => SampleValue::I64(union.sllVal),
+                SignedInt => SampleValue::I32(union.siVal),
+                UnsignedShort => SampleValue::U16(union.usVal),
             }
         }
     }
@@ -201,6 +215,167 @@ impl TryFrom<nvmlPowerSource_t> for PowerSource {
     }
 }
 
+/// The component a thermal sensor measures. See [`crate::Device::thermal_settings()`].
+// Not an `EnumWrapper` because the C enum has a negative `UNKNOWN` variant, so
+// bindgen generates it as a signed type
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ThermalTarget {
+    None,
+    /// The GPU core temperature.
+    Gpu,
+    /// The memory temperature.
+    Memory,
+    /// The power supply temperature.
+    PowerSupply,
+    /// The board ambient temperature.
+    Board,
+    /// The visual computing device board temperature.
+    VcdBoard,
+    /// The visual computing device inlet temperature.
+    VcdInlet,
+    /// The visual computing device outlet temperature.
+    VcdOutlet,
+    All,
+    /// Returned for any value this version of the wrapper doesn't recognize.
+    Unknown,
+}
+
+impl ThermalTarget {
+    /// Returns the C constant equivalent for the given Rust enum variant.
+    pub fn as_c(&self) -> nvmlThermalTarget_t {
+        match *self {
+            Self::None => nvmlThermalTarget_t_NVML_THERMAL_TARGET_NONE,
+            Self::Gpu => nvmlThermalTarget_t_NVML_THERMAL_TARGET_GPU,
+            Self::Memory => nvmlThermalTarget_t_NVML_THERMAL_TARGET_MEMORY,
+            Self::PowerSupply => nvmlThermalTarget_t_NVML_THERMAL_TARGET_POWER_SUPPLY,
+            Self::Board => nvmlThermalTarget_t_NVML_THERMAL_TARGET_BOARD,
+            Self::VcdBoard => nvmlThermalTarget_t_NVML_THERMAL_TARGET_VCD_BOARD,
+            Self::VcdInlet => nvmlThermalTarget_t_NVML_THERMAL_TARGET_VCD_INLET,
+            Self::VcdOutlet => nvmlThermalTarget_t_NVML_THERMAL_TARGET_VCD_OUTLET,
+            Self::All => nvmlThermalTarget_t_NVML_THERMAL_TARGET_ALL,
+            Self::Unknown => nvmlThermalTarget_t_NVML_THERMAL_TARGET_UNKNOWN,
+        }
+    }
+}
+
+impl From<nvmlThermalTarget_t> for ThermalTarget {
+    fn from(data: nvmlThermalTarget_t) -> Self {
+        match data {
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_NONE => Self::None,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_GPU => Self::Gpu,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_MEMORY => Self::Memory,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_POWER_SUPPLY => Self::PowerSupply,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_BOARD => Self::Board,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_VCD_BOARD => Self::VcdBoard,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_VCD_INLET => Self::VcdInlet,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_VCD_OUTLET => Self::VcdOutlet,
+            nvmlThermalTarget_t_NVML_THERMAL_TARGET_ALL => Self::All,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// The controller a thermal sensor is read through. See
+/// [`crate::Device::thermal_settings()`].
+///
+/// NVIDIA doesn't document the variants beyond their names.
+// Not an `EnumWrapper` because the C enum has a negative `UNKNOWN` variant, so
+// bindgen generates it as a signed type
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ThermalController {
+    None,
+    GpuInternal,
+    Adm1032,
+    Adt7461,
+    Max6649,
+    Max1617,
+    Lm99,
+    Lm89,
+    Lm64,
+    G781,
+    Adt7473,
+    SbMax6649,
+    VbiosEvt,
+    Os,
+    NvSysconCanoas,
+    NvSysconE551,
+    Max6649R,
+    Adt7473S,
+    /// Returned for any value this version of the wrapper doesn't recognize.
+    Unknown,
+}
+
+impl ThermalController {
+    /// Returns the C constant equivalent for the given Rust enum variant.
+    pub fn as_c(&self) -> nvmlThermalController_t {
+        match *self {
+            Self::None => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_NONE,
+            Self::GpuInternal => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_GPU_INTERNAL,
+            Self::Adm1032 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADM1032,
+            Self::Adt7461 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADT7461,
+            Self::Max6649 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_MAX6649,
+            Self::Max1617 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_MAX1617,
+            Self::Lm99 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_LM99,
+            Self::Lm89 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_LM89,
+            Self::Lm64 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_LM64,
+            Self::G781 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_G781,
+            Self::Adt7473 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADT7473,
+            Self::SbMax6649 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_SBMAX6649,
+            Self::VbiosEvt => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_VBIOSEVT,
+            Self::Os => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_OS,
+            Self::NvSysconCanoas => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_NVSYSCON_CANOAS,
+            Self::NvSysconE551 => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_NVSYSCON_E551,
+            Self::Max6649R => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_MAX6649R,
+            Self::Adt7473S => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADT7473S,
+            Self::Unknown => nvmlThermalController_t_NVML_THERMAL_CONTROLLER_UNKNOWN,
+        }
+    }
+}
+
+impl From<nvmlThermalController_t> for ThermalController {
+    fn from(data: nvmlThermalController_t) -> Self {
+        match data {
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_NONE => Self::None,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_GPU_INTERNAL => Self::GpuInternal,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADM1032 => Self::Adm1032,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADT7461 => Self::Adt7461,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_MAX6649 => Self::Max6649,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_MAX1617 => Self::Max1617,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_LM99 => Self::Lm99,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_LM89 => Self::Lm89,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_LM64 => Self::Lm64,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_G781 => Self::G781,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADT7473 => Self::Adt7473,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_SBMAX6649 => Self::SbMax6649,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_VBIOSEVT => Self::VbiosEvt,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_OS => Self::Os,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_NVSYSCON_CANOAS => Self::NvSysconCanoas,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_NVSYSCON_E551 => Self::NvSysconE551,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_MAX6649R => Self::Max6649R,
+            nvmlThermalController_t_NVML_THERMAL_CONTROLLER_ADT7473S => Self::Adt7473S,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Whether a `Device`'s driver model is settled or waiting on a reboot.
+///
+/// Returned by [`crate::Device::driver_model_transition()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg(target_os = "windows")]
+pub enum DriverModelTransition {
+    /// No change is pending; the `Device` is running in this driver model.
+    Stable(DriverModel),
+    /// A different driver model has been set and takes effect after a reboot.
+    RebootRequired {
+        current: DriverModel,
+        pending: DriverModel,
+    },
+}
+
 /// Returned by [`crate::Device::architecture()`].
 ///
 /// This is the simplified chip architecture of the device.
@@ -381,3 +556,151 @@ impl TryFrom<nvmlFanControlPolicy_t> for FanControlPolicy {
         }
     }
 }
+
+/// The status of a GRID license expiry.
+// TODO: technically this is an "enum wrapper" but the type on the C side isn't
+// an enum
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LicenseExpiryStatus {
+    /// Expiry information is not available.
+    NotAvailable,
+    /// The license has expired or the expiry information is invalid.
+    Invalid,
+    /// The license expiry is valid.
+    Valid,
+    /// Expiry is not applicable.
+    NotApplicable,
+    /// The license does not expire.
+    Permanent,
+}
+
+impl LicenseExpiryStatus {
+    /// Returns the C constant equivalent for the given Rust enum variant.
+    pub fn as_c(&self) -> c_uint {
+        match *self {
+            Self::NotAvailable => NVML_GRID_LICENSE_EXPIRY_NOT_AVAILABLE,
+            Self::Invalid => NVML_GRID_LICENSE_EXPIRY_INVALID,
+            Self::Valid => NVML_GRID_LICENSE_EXPIRY_VALID,
+            Self::NotApplicable => NVML_GRID_LICENSE_EXPIRY_NOT_APPLICABLE,
+            Self::Permanent => NVML_GRID_LICENSE_EXPIRY_PERMANENT,
+        }
+    }
+}
+
+impl TryFrom<c_uint> for LicenseExpiryStatus {
+    type Error = NvmlError;
+
+    fn try_from(value: c_uint) -> Result<Self, Self::Error> {
+        match value {
+            NVML_GRID_LICENSE_EXPIRY_NOT_AVAILABLE => Ok(Self::NotAvailable),
+            NVML_GRID_LICENSE_EXPIRY_INVALID => Ok(Self::Invalid),
+            NVML_GRID_LICENSE_EXPIRY_VALID => Ok(Self::Valid),
+            NVML_GRID_LICENSE_EXPIRY_NOT_APPLICABLE => Ok(Self::NotApplicable),
+            NVML_GRID_LICENSE_EXPIRY_PERMANENT => Ok(Self::Permanent),
+            _ => Err(NvmlError::UnexpectedVariant(value)),
+        }
+    }
+}
+
+/// The license state of a vGPU instance.
+///
+/// Returned as part of `Device.vgpu_license_info()`.
+// TODO: technically this is an "enum wrapper" but the type on the C side isn't
+// an enum
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LicenseState {
+    Unknown,
+    Uninitialized,
+    /// Unlicensed, but all features are usable.
+    UnlicensedUnrestricted,
+    /// Unlicensed, with restricted features.
+    UnlicensedRestricted,
+    /// Unlicensed; features are no longer usable.
+    Unlicensed,
+    Licensed,
+}
+
+impl LicenseState {
+    /// Returns the C constant equivalent for the given Rust enum variant.
+    pub fn as_c(&self) -> c_uint {
+        match *self {
+            Self::Unknown => NVML_GRID_LICENSE_STATE_UNKNOWN,
+            Self::Uninitialized => NVML_GRID_LICENSE_STATE_UNINITIALIZED,
+            Self::UnlicensedUnrestricted => NVML_GRID_LICENSE_STATE_UNLICENSED_UNRESTRICTED,
+            Self::UnlicensedRestricted => NVML_GRID_LICENSE_STATE_UNLICENSED_RESTRICTED,
+            Self::Unlicensed => NVML_GRID_LICENSE_STATE_UNLICENSED,
+            Self::Licensed => NVML_GRID_LICENSE_STATE_LICENSED,
+        }
+    }
+}
+
+impl TryFrom<c_uint> for LicenseState {
+    type Error = NvmlError;
+
+    fn try_from(value: c_uint) -> Result<Self, Self::Error> {
+        match value {
+            NVML_GRID_LICENSE_STATE_UNKNOWN => Ok(Self::Unknown),
+            NVML_GRID_LICENSE_STATE_UNINITIALIZED => Ok(Self::Uninitialized),
+            NVML_GRID_LICENSE_STATE_UNLICENSED_UNRESTRICTED => Ok(Self::UnlicensedUnrestricted),
+            NVML_GRID_LICENSE_STATE_UNLICENSED_RESTRICTED => Ok(Self::UnlicensedRestricted),
+            NVML_GRID_LICENSE_STATE_UNLICENSED => Ok(Self::Unlicensed),
+            NVML_GRID_LICENSE_STATE_LICENSED => Ok(Self::Licensed),
+            _ => Err(NvmlError::UnexpectedVariant(value)),
+        }
+    }
+}
+
+/// The state of a GPU's registration with the NvLink fabric.
+///
+/// Returned as part of `Device.gpu_fabric_info()`.
+// TODO: technically this is an "enum wrapper" but the type on the C side isn't
+// an enum
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GpuFabricState {
+    NotSupported,
+    NotStarted,
+    InProgress,
+    Completed,
+}
+
+impl GpuFabricState {
+    /// Returns the C constant equivalent for the given Rust enum variant.
+    pub fn as_c(&self) -> c_uint {
+        match *self {
+            Self::NotSupported => NVML_GPU_FABRIC_STATE_NOT_SUPPORTED,
+            Self::NotStarted => NVML_GPU_FABRIC_STATE_NOT_STARTED,
+            Self::InProgress => NVML_GPU_FABRIC_STATE_IN_PROGRESS,
+            Self::Completed => NVML_GPU_FABRIC_STATE_COMPLETED,
+        }
+    }
+}
+
+impl TryFrom<c_uint> for GpuFabricState {
+    type Error = NvmlError;
+
+    fn try_from(value: c_uint) -> Result<Self, Self::Error> {
+        match value {
+            NVML_GPU_FABRIC_STATE_NOT_SUPPORTED => Ok(Self::NotSupported),
+            NVML_GPU_FABRIC_STATE_NOT_STARTED => Ok(Self::NotStarted),
+            NVML_GPU_FABRIC_STATE_IN_PROGRESS => Ok(Self::InProgress),
+            NVML_GPU_FABRIC_STATE_COMPLETED => Ok(Self::Completed),
+            _ => Err(NvmlError::UnexpectedVariant(value)),
+        }
+    }
+}
+
+/// Formats for rendering a PCI bus ID.
+///
+/// Used with `Device.pci_bus_id()` and `PciInfo.formatted_bus_id()`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BusIdFormat {
+    /// A 4-digit domain, e.g. `0000:01:00.0`.
+    Legacy,
+    /// An 8-digit domain, e.g. `00000000:01:00.0`, as used by current NVML and
+    /// `nvidia-smi` versions.
+    Extended,
+}
@@ -1,5 +1,8 @@
+use crate::error::NvmlError;
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::os::raw::c_uint;
 
 /// Used to specify the counter in `NvLink.set_utilization_control_for()`
 ///
@@ -11,3 +14,45 @@ pub enum Counter {
     Zero = 0,
     One = 1,
 }
+
+/// The global NvLink bandwidth mode. See `Nvml.nvlink_bw_mode()`.
+///
+/// Values mirror the `NVML_NVLINK_BW_MODE_*` constants, which are not present
+/// in the header the bindings are generated from.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NvLinkBwMode {
+    /// Full bandwidth.
+    Full = 0,
+    /// NvLink is turned off.
+    Off = 1,
+    /// Minimum bandwidth.
+    Min = 2,
+    /// Half bandwidth.
+    Half = 3,
+    /// Three-quarter bandwidth.
+    ThreeQuarter = 4,
+}
+
+impl NvLinkBwMode {
+    /// Returns the C constant equivalent for the given Rust enum variant.
+    pub fn as_c(&self) -> c_uint {
+        *self as c_uint
+    }
+}
+
+impl TryFrom<c_uint> for NvLinkBwMode {
+    type Error = NvmlError;
+
+    fn try_from(value: c_uint) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Full),
+            1 => Ok(Self::Off),
+            2 => Ok(Self::Min),
+            3 => Ok(Self::Half),
+            4 => Ok(Self::ThreeQuarter),
+            _ => Err(NvmlError::UnexpectedVariant(value)),
+        }
+    }
+}
@@ -39,13 +39,17 @@ pub enum NvmlError {
     /**
     A function symbol failed to load.
 
-    This variant is constructed with a textual description of a
-    `libloading::Error`. The error variant itself can't be provided because we're
+    This usually means that the loaded NVML library is too old to provide the
+    function.
+
+    `name` is the name of the NVML function that could not be loaded (e.g.
+    `"nvmlDeviceGetTotalEnergyConsumption"`). `error` is a textual description
+    of the `libloading::Error`. The error itself can't be provided because we're
     unable to take ownership of the error when attempting to use a symbol, and
     `libloading::Error` doesn't impl `Clone`.
     */
-    #[error("function symbol failed to load: {0}")]
-    FailedToLoadSymbol(String),
+    #[error("function symbol `{name}` failed to load: {error}")]
+    FailedToLoadSymbol { name: &'static str, error: String },
 
     #[error("max string length was {max_len} but string length is {actual_len}")]
     StringTooLong { max_len: usize, actual_len: usize },
@@ -77,6 +81,25 @@ pub enum NvmlError {
     #[error("a call to `PciInfo.try_into_c()` failed")]
     PciInfoToCFailed,
 
+    /**
+    A confidential compute attestation report could not be parsed.
+
+    This error is specific to this Rust wrapper. The single field names the
+    part of the report that was truncated or inconsistent.
+    */
+    #[error("malformed attestation report: {0}")]
+    MalformedAttestationReport(&'static str),
+
+    /**
+    A fixed-size C string buffer did not contain a nul terminator.
+
+    This error is specific to this Rust wrapper. It can be returned when
+    parsing buffers supplied by the caller, such as in
+    `VgpuMetadata::try_from_raw()`.
+    */
+    #[error("string buffer is missing a nul terminator")]
+    MissingNulTerminator,
+
     #[error("NVML was not first initialized with `Nvml::init()`")]
     Uninitialized,
 
@@ -251,17 +274,26 @@ impl From<NvmlError> for nvmlReturn_t {
             Utf8Error(_)
             | NulError(_)
             | LibloadingError(_)
-            | FailedToLoadSymbol(_)
+            | FailedToLoadSymbol { .. }
             | StringTooLong { .. }
             | IncorrectBits(_)
             | SetReleaseFailed
             | GetPciInfoFailed
-            | PciInfoToCFailed => nvmlReturn_enum_NVML_ERROR_UNKNOWN,
+            | PciInfoToCFailed
+            | MalformedAttestationReport(_)
+            | MissingNulTerminator => nvmlReturn_enum_NVML_ERROR_UNKNOWN,
         }
     }
 }
 
-/// Helper to map a `&libloading::Error` into an `NvmlError`
-pub fn nvml_sym<'a, T>(sym: Result<&'a T, &libloading::Error>) -> Result<&'a T, NvmlError> {
-    sym.map_err(|e| NvmlError::FailedToLoadSymbol(e.to_string()))
+/// Helper to map a `&libloading::Error` into an `NvmlError`, recording the
+/// name of the symbol that failed to load
+pub fn nvml_sym<'a, T>(
+    sym: Result<&'a T, &libloading::Error>,
+    name: &'static str,
+) -> Result<&'a T, NvmlError> {
+    sym.map_err(|e| NvmlError::FailedToLoadSymbol {
+        name,
+        error: e.to_string(),
+    })
 }
@@ -3,6 +3,7 @@ use crate::ffi::bindings::*;
 use crate::Nvml;
 
 use std::mem;
+use std::time::{Duration, Instant};
 
 use crate::struct_wrappers::event::EventData;
 
@@ -72,7 +73,7 @@ impl<'nvml> EventSet<'nvml> {
     // Checked against local
     #[doc(alias = "nvmlEventSetFree")]
     pub fn release_events(self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlEventSetFree.as_ref())?;
+        let sym = nvml_sym(self.nvml.lib.nvmlEventSetFree.as_ref(), "nvmlEventSetFree")?;
 
         unsafe {
             nvml_try(sym(self.set))?;
@@ -112,7 +113,10 @@ impl<'nvml> EventSet<'nvml> {
     // Checked against local
     #[doc(alias = "nvmlEventSetWait_v2")]
     pub fn wait(&self, timeout_ms: u32) -> Result<EventData<'nvml>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlEventSetWait_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlEventSetWait_v2.as_ref(),
+            "nvmlEventSetWait_v2",
+        )?;
 
         unsafe {
             let mut data: nvmlEventData_t = mem::zeroed();
@@ -122,6 +126,43 @@ impl<'nvml> EventSet<'nvml> {
         }
     }
 
+    /**
+    Returns an iterator that waits on events until `timeout` has elapsed in
+    total, yielding each event as it arrives.
+
+    Running out of time ends the iteration rather than producing a `Timeout`
+    error, so the iterator can be used directly in a `for` loop. Any other
+    error is yielded once and ends the iteration.
+
+    ```no_run
+    # use nvml_wrapper::Nvml;
+    # use nvml_wrapper::error::*;
+    # use nvml_wrapper::bitmasks::event::EventTypes;
+    # use std::time::Duration;
+    # fn main() -> Result<(), NvmlErrorWithSource> {
+    let nvml = Nvml::init()?;
+    let device = nvml.device_by_index(0)?;
+    let set = device.register_events(EventTypes::PSTATE_CHANGE, nvml.create_event_set()?)?;
+
+    for event in set.poll_iter(Duration::from_secs(5)) {
+        println!("{:?}", event?);
+    }
+    # Ok(())
+    # }
+    ```
+
+    # Device Support
+
+    Supports Fermi and newer fully supported devices.
+    */
+    pub fn poll_iter(&self, timeout: Duration) -> PollIter<'_, 'nvml> {
+        PollIter {
+            set: self,
+            deadline: Instant::now().checked_add(timeout),
+            done: false,
+        }
+    }
+
     /// Get the raw device handle contained in this struct
     ///
     /// Sometimes necessary for C interop.
@@ -137,6 +178,56 @@ impl<'nvml> EventSet<'nvml> {
     }
 }
 
+/**
+Iterator over the events delivered to an `EventSet` within a timeout.
+
+Obtain this via `EventSet.poll_iter()`.
+*/
+#[derive(Debug)]
+pub struct PollIter<'set, 'nvml> {
+    set: &'set EventSet<'nvml>,
+    // `None` if the timeout is too far in the future to represent
+    deadline: Option<Instant>,
+    done: bool,
+}
+
+impl<'set, 'nvml> Iterator for PollIter<'set, 'nvml> {
+    type Item = Result<EventData<'nvml>, NvmlError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let timeout_ms = match self.deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break;
+                    }
+
+                    // Round up so sub-millisecond remainders still wait
+                    ((remaining.as_micros() + 999) / 1000).min(u32::MAX as u128) as u32
+                }
+                None => u32::MAX,
+            };
+
+            match self.set.wait(timeout_ms) {
+                Ok(data) => return Some(Ok(data)),
+                // NVML also reports an interrupt as a timeout, so keep waiting
+                // until the deadline has actually passed
+                Err(NvmlError::Timeout) => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+impl<'set, 'nvml> std::iter::FusedIterator for PollIter<'set, 'nvml> {}
+
 /// This `Drop` implementation ignores errors! Use the `.release_events()`
 /// method on the `EventSet` struct if you care about handling them.
 impl<'nvml> Drop for EventSet<'nvml> {
@@ -196,4 +287,25 @@ mod test {
 
         print!("{:?} ...", data);
     }
+
+    #[test]
+    fn poll_iter() {
+        use std::time::Duration;
+
+        let nvml = nvml();
+        let device = device(&nvml);
+        let set = nvml.create_event_set().expect("event set");
+        let set = device
+            .register_events(
+                EventTypes::PSTATE_CHANGE
+                    | EventTypes::CRITICAL_XID_ERROR
+                    | EventTypes::CLOCK_CHANGE,
+                set,
+            )
+            .expect("registration");
+
+        for event in set.poll_iter(Duration::from_secs(1)) {
+            print!("{:?} ...", event.expect("event"));
+        }
+    }
 }
@@ -40,7 +40,7 @@ impl<'nvml> GpmSample<'nvml> {
     /// * `Unknown`, on any unexpected error
     #[doc(alias = "nvmlGpmSampleAlloc")]
     pub(crate) fn alloc(nvml: &'nvml Nvml) -> Result<Self, NvmlError> {
-        let sym = nvml_sym(nvml.lib.nvmlGpmSampleAlloc.as_ref())?;
+        let sym = nvml_sym(nvml.lib.nvmlGpmSampleAlloc.as_ref(), "nvmlGpmSampleAlloc")?;
 
         unsafe {
             let mut sample: nvmlGpmSample_t = mem::zeroed();
@@ -61,7 +61,10 @@ impl<'nvml> GpmSample<'nvml> {
     */
     #[doc(alias = "nvmlGpmSampleFree")]
     pub fn free(self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlGpmSampleFree.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlGpmSampleFree.as_ref(),
+            "nvmlGpmSampleFree",
+        )?;
 
         unsafe {
             nvml_try(sym(self.sample))?;
@@ -139,7 +142,7 @@ pub fn gpm_metrics_get<'nvml>(
         nvmlGpmMetricId_t_NVML_GPM_METRIC_MAX
     );
 
-    let sym = nvml_sym(nvml.lib.nvmlGpmMetricsGet.as_ref())?;
+    let sym = nvml_sym(nvml.lib.nvmlGpmMetricsGet.as_ref(), "nvmlGpmMetricsGet")?;
 
     unsafe {
         let mut request: nvmlGpmMetricsGet_t = mem::zeroed();
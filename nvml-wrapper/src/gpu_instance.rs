@@ -0,0 +1,739 @@
+use crate::Device;
+
+use crate::error::{nvml_sym, nvml_try, NvmlError};
+use crate::ffi::bindings::*;
+use crate::struct_wrappers::device::{
+    ComputeInstanceInfo, ComputeInstancePlacement, ComputeInstanceProfileInfo, GpuInstanceInfo,
+};
+
+use std::{mem, os::raw::c_uint, ptr};
+
+use static_assertions::assert_impl_all;
+
+/**
+Struct that represents a MIG GPU instance on a `Device`.
+
+Obtain this via `Device.create_gpu_instance()` or `Device.gpu_instances()`.
+
+GPU instances outlive the handles that refer to them; dropping a `GpuInstance`
+does not destroy the underlying instance. Call `.destroy()` for that.
+
+Lifetimes are used to enforce that each `GpuInstance` cannot be used after the
+`Device` it was obtained from is dropped:
+
+```compile_fail
+use nvml_wrapper::Nvml;
+# use nvml_wrapper::error::*;
+
+# fn main() -> Result<(), NvmlError> {
+let nvml = Nvml::init()?;
+let device = nvml.device_by_index(0)?;
+let gpu_instance = device.create_gpu_instance(0)?;
+
+drop(device);
+
+// This won't compile
+gpu_instance.info()?;
+# Ok(())
+# }
+```
+*/
+#[derive(Debug)]
+pub struct GpuInstance<'dev> {
+    device: &'dev Device<'dev>,
+    handle: nvmlGpuInstance_t,
+}
+
+unsafe impl Send for GpuInstance<'_> {}
+unsafe impl Sync for GpuInstance<'_> {}
+
+assert_impl_all!(GpuInstance: Send, Sync);
+
+impl<'dev> GpuInstance<'dev> {
+    /// Create a new GPU instance wrapper.
+    ///
+    /// You probably don't need to use this yourself, but rather through
+    /// [`Device::create_gpu_instance`] and [`Device::gpu_instances`].
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid GPU instance belonging to `device`.
+    pub unsafe fn new(device: &'dev Device<'dev>, handle: nvmlGpuInstance_t) -> Self {
+        Self { device, handle }
+    }
+
+    /// Access the `Device` this struct belongs to.
+    pub fn device(&self) -> &'dev Device<'dev> {
+        self.device
+    }
+
+    /// Get the raw GPU instance handle contained in this struct.
+    ///
+    /// Sometimes necessary for C interop.
+    ///
+    /// # Safety
+    ///
+    /// This is unsafe to prevent it from being used without care.
+    pub unsafe fn handle(&self) -> nvmlGpuInstance_t {
+        self.handle
+    }
+
+    /**
+    Destroys this GPU instance.
+
+    All compute instances within it must have been destroyed first.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` is invalid
+    * `NotSupported`, if MIG is not supported by the `Device`
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `InUse`, if this `GpuInstance` is in use; destroy its compute instances
+      and stop any processes running on it first
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceDestroy")]
+    pub fn destroy(self) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlGpuInstanceDestroy.as_ref(),
+            "nvmlGpuInstanceDestroy",
+        )?;
+
+        unsafe { nvml_try(sym(self.handle)) }
+    }
+
+    /**
+    Gets information about this GPU instance, including its ID.
+
+    The ID can be persisted and passed to `Device.gpu_instance_by_id()` to
+    obtain this `GpuInstance` again later.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` is invalid
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceGetInfo")]
+    pub fn info(&self) -> Result<GpuInstanceInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlGpuInstanceGetInfo.as_ref(),
+            "nvmlGpuInstanceGetInfo",
+        )?;
+
+        unsafe {
+            let mut info: nvmlGpuInstanceInfo_t = mem::zeroed();
+            nvml_try(sym(self.handle, &mut info))?;
+
+            Ok(info.into())
+        }
+    }
+
+    /**
+    Gets compute instance profile info for the given compute instance profile
+    and engine profile.
+
+    `profile` is one of the `NVML_COMPUTE_INSTANCE_PROFILE_*` constants and
+    `eng_profile` is one of the `NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_*`
+    constants.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` or `profile` or `eng_profile` is invalid
+    * `NotSupported`, if `profile` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceGetComputeInstanceProfileInfo")]
+    pub fn compute_instance_profile_info(
+        &self,
+        profile: u32,
+        eng_profile: u32,
+    ) -> Result<ComputeInstanceProfileInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceGetComputeInstanceProfileInfo
+                .as_ref(),
+            "nvmlGpuInstanceGetComputeInstanceProfileInfo",
+        )?;
+
+        unsafe {
+            let mut info: nvmlComputeInstanceProfileInfo_t = mem::zeroed();
+            nvml_try(sym(self.handle, profile, eng_profile, &mut info))?;
+
+            Ok(info.into())
+        }
+    }
+
+    /**
+    Gets how many more compute instances with the given compute instance
+    profile can currently be created within this GPU instance.
+
+    `profile_id` is the `id` of a `ComputeInstanceProfileInfo` obtained from
+    `.compute_instance_profile_info()`.
+
+    Check this before `.create_compute_instance()` to avoid failing for lack
+    of resources.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` or `profile_id` is invalid
+    * `NotSupported`, if `profile_id` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceGetComputeInstanceRemainingCapacity")]
+    pub fn compute_instance_remaining_capacity(&self, profile_id: u32) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceGetComputeInstanceRemainingCapacity
+                .as_ref(),
+            "nvmlGpuInstanceGetComputeInstanceRemainingCapacity",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = 0;
+            nvml_try(sym(self.handle, profile_id, &mut count))?;
+
+            Ok(count)
+        }
+    }
+
+    /**
+    Creates a compute instance with the given compute instance profile within
+    this GPU instance.
+
+    `profile_id` is the `id` of a `ComputeInstanceProfileInfo` obtained from
+    `.compute_instance_profile_info()`.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` or `profile_id` is invalid
+    * `NotSupported`, if `profile_id` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `UnexpectedVariant`, if the requested compute instance could not be
+      created because there aren't enough resources left in this `GpuInstance`
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceCreateComputeInstance")]
+    pub fn create_compute_instance(
+        &self,
+        profile_id: u32,
+    ) -> Result<ComputeInstance<'_>, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceCreateComputeInstance
+                .as_ref(),
+            "nvmlGpuInstanceCreateComputeInstance",
+        )?;
+
+        unsafe {
+            let mut handle: nvmlComputeInstance_t = mem::zeroed();
+            nvml_try(sym(self.handle, profile_id, &mut handle))?;
+
+            Ok(ComputeInstance::new(self, handle))
+        }
+    }
+
+    /**
+    Gets the placements that compute instances with the given compute
+    instance profile may occupy within this GPU instance.
+
+    `profile_id` is the `id` of a `ComputeInstanceProfileInfo` obtained from
+    `.compute_instance_profile_info()`.
+
+    This returns every possible placement for the profile, including ones that
+    overlap with existing compute instances.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` or `profile_id` is invalid
+    * `NotSupported`, if MIG is not enabled or `profile_id` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceGetComputeInstancePossiblePlacements")]
+    pub fn compute_instance_possible_placements(
+        &self,
+        profile_id: u32,
+    ) -> Result<Vec<ComputeInstancePlacement>, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceGetComputeInstancePossiblePlacements
+                .as_ref(),
+            "nvmlGpuInstanceGetComputeInstancePossiblePlacements",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = 0;
+            nvml_try(sym(self.handle, profile_id, ptr::null_mut(), &mut count))?;
+
+            let mut placements: Vec<nvmlComputeInstancePlacement_t> =
+                vec![mem::zeroed(); count as usize];
+            nvml_try(sym(
+                self.handle,
+                profile_id,
+                placements.as_mut_ptr(),
+                &mut count,
+            ))?;
+
+            placements.truncate(count as usize);
+            Ok(placements
+                .into_iter()
+                .map(ComputeInstancePlacement::from)
+                .collect())
+        }
+    }
+
+    /**
+    Creates a compute instance with the given compute instance profile at the
+    given placement within this GPU instance.
+
+    `profile_id` is the `id` of a `ComputeInstanceProfileInfo` obtained from
+    `.compute_instance_profile_info()`. `placement` should be one of the placements returned by
+    `.compute_instance_possible_placements()` that doesn't overlap with an
+    existing compute instance.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance`, `profile_id` or `placement` is invalid
+    * `NotSupported`, if MIG is not enabled or `profile_id` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `UnexpectedVariant`, if the requested compute instance could not be
+      created because `placement` is already occupied
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceCreateComputeInstanceWithPlacement")]
+    pub fn create_compute_instance_with_placement(
+        &self,
+        profile_id: u32,
+        placement: &ComputeInstancePlacement,
+    ) -> Result<ComputeInstance<'_>, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceCreateComputeInstanceWithPlacement
+                .as_ref(),
+            "nvmlGpuInstanceCreateComputeInstanceWithPlacement",
+        )?;
+
+        unsafe {
+            let raw_placement = nvmlComputeInstancePlacement_t {
+                start: placement.start,
+                size: placement.size,
+            };
+            let mut handle: nvmlComputeInstance_t = mem::zeroed();
+            nvml_try(sym(self.handle, profile_id, &raw_placement, &mut handle))?;
+
+            Ok(ComputeInstance::new(self, handle))
+        }
+    }
+
+    /**
+    Gets the compute instance with the given ID within this GPU instance.
+
+    Use this to reattach to a compute instance whose ID was obtained from
+    `ComputeInstance.info()` earlier, e.g. before a process restart.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` or `id` is invalid
+    * `NotSupported`, if MIG is not enabled on the `Device`
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `NotFound`, if no compute instance with the given `id` exists
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceGetComputeInstanceById")]
+    pub fn compute_instance_by_id(&self, id: u32) -> Result<ComputeInstance<'_>, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceGetComputeInstanceById
+                .as_ref(),
+            "nvmlGpuInstanceGetComputeInstanceById",
+        )?;
+
+        unsafe {
+            let mut handle: nvmlComputeInstance_t = mem::zeroed();
+            nvml_try(sym(self.handle, id, &mut handle))?;
+
+            Ok(ComputeInstance::new(self, handle))
+        }
+    }
+
+    /**
+    Gets the existing compute instances with the given compute instance
+    profile within this GPU instance.
+
+    `profile` is a `ComputeInstanceProfileInfo` obtained from
+    `.compute_instance_profile_info()`. Its `id` is passed to NVML and its
+    `instance_count` sizes the buffer.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `GpuInstance` or `profile` is invalid
+    * `NotSupported`, if `profile` isn't supported
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlGpuInstanceGetComputeInstances")]
+    pub fn compute_instances(
+        &self,
+        profile: &ComputeInstanceProfileInfo,
+    ) -> Result<Vec<ComputeInstance<'_>>, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlGpuInstanceGetComputeInstances
+                .as_ref(),
+            "nvmlGpuInstanceGetComputeInstances",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = 0;
+            // The buffer must be able to hold every instance the profile supports
+            let mut handles: Vec<nvmlComputeInstance_t> =
+                vec![mem::zeroed(); profile.instance_count as usize];
+
+            nvml_try(sym(
+                self.handle,
+                profile.id,
+                handles.as_mut_ptr(),
+                &mut count,
+            ))?;
+
+            handles.truncate(count as usize);
+            Ok(handles
+                .into_iter()
+                .map(|handle| ComputeInstance::new(self, handle))
+                .collect())
+        }
+    }
+}
+
+/**
+Struct that represents a MIG compute instance within a `GpuInstance`.
+
+Obtain this via `GpuInstance.create_compute_instance()` or
+`GpuInstance.compute_instances()`.
+
+As with `GpuInstance`, dropping a `ComputeInstance` does not destroy the
+underlying instance; call `.destroy()` for that.
+*/
+#[derive(Debug)]
+pub struct ComputeInstance<'gi> {
+    gpu_instance: &'gi GpuInstance<'gi>,
+    handle: nvmlComputeInstance_t,
+}
+
+unsafe impl Send for ComputeInstance<'_> {}
+unsafe impl Sync for ComputeInstance<'_> {}
+
+assert_impl_all!(ComputeInstance: Send, Sync);
+
+impl<'gi> ComputeInstance<'gi> {
+    /// Create a new compute instance wrapper.
+    ///
+    /// You probably don't need to use this yourself, but rather through
+    /// [`GpuInstance::create_compute_instance`] and
+    /// [`GpuInstance::compute_instances`].
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid compute instance belonging to `gpu_instance`.
+    pub unsafe fn new(gpu_instance: &'gi GpuInstance<'gi>, handle: nvmlComputeInstance_t) -> Self {
+        Self {
+            gpu_instance,
+            handle,
+        }
+    }
+
+    /// Access the `GpuInstance` this struct belongs to.
+    pub fn gpu_instance(&self) -> &'gi GpuInstance<'gi> {
+        self.gpu_instance
+    }
+
+    /// Get the raw compute instance handle contained in this struct.
+    ///
+    /// Sometimes necessary for C interop.
+    ///
+    /// # Safety
+    ///
+    /// This is unsafe to prevent it from being used without care.
+    pub unsafe fn handle(&self) -> nvmlComputeInstance_t {
+        self.handle
+    }
+
+    /**
+    Gets information about this compute instance, including its ID.
+
+    The ID can be persisted and passed to `GpuInstance.compute_instance_by_id()`
+    to obtain this `ComputeInstance` again later.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `ComputeInstance` is invalid
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlComputeInstanceGetInfo_v2")]
+    pub fn info(&self) -> Result<ComputeInstanceInfo, NvmlError> {
+        let sym = nvml_sym(
+            self.gpu_instance
+                .device
+                .nvml()
+                .lib
+                .nvmlComputeInstanceGetInfo_v2
+                .as_ref(),
+            "nvmlComputeInstanceGetInfo_v2",
+        )?;
+
+        unsafe {
+            let mut info: nvmlComputeInstanceInfo_t = mem::zeroed();
+            nvml_try(sym(self.handle, &mut info))?;
+
+            Ok(info.into())
+        }
+    }
+
+    /**
+    Destroys this compute instance.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if this `ComputeInstance` is invalid
+    * `NotSupported`, if MIG is not supported by the `Device`
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `InUse`, if processes are still running on this `ComputeInstance`
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Ampere and newer fully supported devices.
+
+    # Platform Support
+
+    Only supports Linux.
+    */
+    #[doc(alias = "nvmlComputeInstanceDestroy")]
+    pub fn destroy(self) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.gpu_instance
+                .device
+                .nvml()
+                .lib
+                .nvmlComputeInstanceDestroy
+                .as_ref(),
+            "nvmlComputeInstanceDestroy",
+        )?;
+
+        unsafe { nvml_try(sym(self.handle)) }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+#[deny(unused_mut)]
+mod test {
+    use crate::ffi::bindings::*;
+    use crate::test_utils::*;
+
+    #[test]
+    #[ignore = "requires an existing MIG GPU instance"]
+    fn compute_instance_profile_info() {
+        let nvml = nvml();
+        test_with_gpu_instance(3, &nvml, |gi| {
+            gi.compute_instance_profile_info(
+                NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED,
+            )
+        })
+    }
+
+    #[test]
+    #[ignore = "requires an existing MIG GPU instance"]
+    fn info() {
+        let nvml = nvml();
+        test_with_gpu_instance(3, &nvml, |gi| gi.info())
+    }
+
+    #[test]
+    #[ignore = "requires an existing MIG GPU instance"]
+    fn compute_instance_by_id() {
+        let nvml = nvml();
+        test_with_gpu_instance(3, &nvml, |gi| {
+            gi.compute_instance_by_id(0).and_then(|ci| ci.info())
+        })
+    }
+
+    #[test]
+    #[ignore = "requires an existing MIG GPU instance"]
+    fn compute_instance_remaining_capacity() {
+        let nvml = nvml();
+        test_with_gpu_instance(3, &nvml, |gi| {
+            let profile = gi.compute_instance_profile_info(
+                NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED,
+            )?;
+
+            gi.compute_instance_remaining_capacity(profile.id)
+        })
+    }
+
+    #[test]
+    #[ignore = "requires an existing MIG GPU instance"]
+    fn compute_instance_possible_placements() {
+        let nvml = nvml();
+        test_with_gpu_instance(3, &nvml, |gi| {
+            let profile = gi.compute_instance_profile_info(
+                NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED,
+            )?;
+
+            gi.compute_instance_possible_placements(profile.id)
+        })
+    }
+
+    #[test]
+    #[ignore = "requires an existing MIG GPU instance"]
+    fn compute_instances() {
+        let nvml = nvml();
+        test_with_gpu_instance(3, &nvml, |gi| {
+            let profile = gi.compute_instance_profile_info(
+                NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED,
+            )?;
+
+            gi.compute_instances(&profile)
+                .map(|instances| instances.len())
+        })
+    }
+
+    // This modifies device state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn create_and_destroy_compute_instance() {
+        let nvml = nvml();
+        let device = device(&nvml);
+        let gi_profile = device
+            .profile_info(NVML_GPU_INSTANCE_PROFILE_1_SLICE)
+            .expect("gpu instance profile info");
+        let gi = device
+            .gpu_instances(&gi_profile)
+            .expect("gpu instances")
+            .into_iter()
+            .next()
+            .expect("a gpu instance");
+        let ci_profile = gi
+            .compute_instance_profile_info(
+                NVML_COMPUTE_INSTANCE_PROFILE_1_SLICE,
+                NVML_COMPUTE_INSTANCE_ENGINE_PROFILE_SHARED,
+            )
+            .expect("compute instance profile info");
+
+        gi.create_compute_instance(ci_profile.id)
+            .expect("created compute instance")
+            .destroy()
+            .expect("destroyed compute instance")
+    }
+}
@@ -2,3 +2,6 @@
 pub mod event_loop;
 #[cfg(target_os = "linux")]
 pub use self::event_loop::{Event, EventLoop, EventLoopProvider};
+pub mod snapshot;
+
+pub use self::snapshot::{DeviceMetrics, Metric};
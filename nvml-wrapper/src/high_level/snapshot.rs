@@ -0,0 +1,128 @@
+/*!
+Batch queries of common metrics across every `Device`.
+
+```no_run
+# use nvml_wrapper::Nvml;
+# use nvml_wrapper::error::NvmlError;
+use nvml_wrapper::high_level::Metric;
+
+# fn main() -> Result<(), NvmlError> {
+let nvml = Nvml::init()?;
+
+for snapshot in nvml.snapshot_all(&[Metric::Temperature, Metric::Power])? {
+    println!(
+        "GPU {}: {:?} C, {:?} mW",
+        snapshot.index, snapshot.temperature, snapshot.power
+    );
+}
+# Ok(())
+# }
+```
+*/
+
+use crate::enum_wrappers::device::TemperatureSensor;
+use crate::error::NvmlError;
+use crate::struct_wrappers::device::Utilization;
+use crate::structs::device::CurrentClocks;
+use crate::{Device, Nvml};
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// A metric that can be requested from `Nvml.snapshot_all()`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Metric {
+    /// GPU core temperature in degrees C, from `Device.temperature()`.
+    Temperature,
+    /// Power usage in milliwatts, from `Device.power_usage()`.
+    Power,
+    /// GPU and memory utilization, from `Device.utilization_rates()`.
+    Utilization,
+    /// Used memory in bytes, from `Device.memory_info()`.
+    MemoryUsed,
+    /// Speed of each fan as a percentage, from `Device.fan_speed()`.
+    FanSpeed,
+    /// Current clock speeds in MHz, from `Device.all_clocks()`.
+    Clocks,
+}
+
+/**
+A snapshot of the metrics requested from `Nvml.snapshot_all()` for a single
+`Device`.
+
+Each metric is `None` if it was not requested or if querying it failed (most
+commonly because the `Device` doesn't support it).
+*/
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeviceMetrics {
+    /// The NVML index of the `Device` this snapshot is for.
+    pub index: u32,
+    pub temperature: Option<u32>,
+    pub power: Option<u32>,
+    pub utilization: Option<Utilization>,
+    pub memory_used: Option<u64>,
+    pub fan_speeds: Option<Vec<u32>>,
+    pub clocks: Option<CurrentClocks>,
+}
+
+impl DeviceMetrics {
+    fn query(index: u32, device: &Device, metrics: &[Metric]) -> Self {
+        let wants = |metric| metrics.contains(&metric);
+
+        Self {
+            index,
+            temperature: wants(Metric::Temperature)
+                .then(|| device.temperature(TemperatureSensor::Gpu).ok())
+                .flatten(),
+            power: wants(Metric::Power)
+                .then(|| device.power_usage().ok())
+                .flatten(),
+            utilization: wants(Metric::Utilization)
+                .then(|| device.utilization_rates().ok())
+                .flatten(),
+            memory_used: wants(Metric::MemoryUsed)
+                .then(|| device.memory_info().ok().map(|m| m.used))
+                .flatten(),
+            fan_speeds: wants(Metric::FanSpeed)
+                .then(|| fan_speeds(device).ok())
+                .flatten(),
+            clocks: wants(Metric::Clocks)
+                .then(|| device.all_clocks().ok())
+                .flatten(),
+        }
+    }
+}
+
+fn fan_speeds(device: &Device) -> Result<Vec<u32>, NvmlError> {
+    (0..device.num_fans()?)
+        .map(|i| device.fan_speed(i))
+        .collect()
+}
+
+impl Nvml {
+    /**
+    Takes a snapshot of the requested metrics for every `Device` on the system.
+
+    Devices are enumerated once and only the NVML functions backing the
+    requested `metrics` are called. Snapshots are returned in index order.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `NoPermission`, if the user doesn't have permission to access a `Device`
+    * `GpuLost`, if a `Device` has fallen off the bus or is otherwise inaccessible
+    * `Unknown`, on any unexpected error
+
+    Failing to query an individual metric is not an error; that metric is
+    `None` in the returned snapshot instead.
+    */
+    pub fn snapshot_all(&self, metrics: &[Metric]) -> Result<Vec<DeviceMetrics>, NvmlError> {
+        (0..self.device_count()?)
+            .map(|index| {
+                let device = self.device_by_index(index)?;
+                Ok(DeviceMetrics::query(index, &device, metrics))
+            })
+            .collect()
+    }
+}
@@ -103,6 +103,8 @@ pub mod enums;
 pub mod error;
 pub mod event;
 pub mod gpm;
+#[cfg(target_os = "linux")]
+pub mod gpu_instance;
 pub mod high_level;
 pub mod nv_link;
 pub mod struct_wrappers;
@@ -116,12 +118,46 @@ pub mod vgpu;
 pub use crate::device::Device;
 pub use crate::event::EventSet;
 pub use crate::gpm::GpmSample;
+#[cfg(target_os = "linux")]
+pub use crate::gpu_instance::{ComputeInstance, GpuInstance};
 pub use crate::nv_link::NvLink;
 pub use crate::unit::Unit;
 
 /// Re-exports from `nvml-wrapper-sys` that are necessary for use of this wrapper.
 pub mod sys_exports {
-    /// Use these constants to populate the `structs::device::FieldId` newtype.
+    /**
+    Use these constants to populate the `structs::device::FieldId` newtype.
+
+    `FieldId::name()` maps an ID back to its constant name for logging.
+
+    Commonly used IDs and the values they report (the value type of each
+    sample is given by the `SampleValue` variant it's returned as):
+
+    | Constant | Value |
+    |----------|-------|
+    | `NVML_FI_DEV_POWER_INSTANT` | Current power draw in milliwatts |
+    | `NVML_FI_DEV_POWER_AVERAGE` | Power draw averaged over 1 second in milliwatts (Ampere and newer, except GA100) |
+    | `NVML_FI_DEV_POWER_CURRENT_LIMIT` | Currently enforced power limit in milliwatts |
+    | `NVML_FI_DEV_TOTAL_ENERGY_CONSUMPTION` | Energy consumed since the driver was last reloaded in millijoules |
+    | `NVML_FI_DEV_PCIE_COUNT_TX_BYTES` | PCIe bytes transmitted; may wrap |
+    | `NVML_FI_DEV_PCIE_COUNT_RX_BYTES` | PCIe bytes received; may wrap |
+    | `NVML_FI_DEV_PCIE_REPLAY_COUNTER` | PCIe replay count |
+    | `NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_TX` | NvLink data transmitted in KiB |
+    | `NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_RX` | NvLink data received in KiB |
+    | `NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_TOTAL` | NvLink data CRC errors across all lanes |
+    | `NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_TOTAL` | NvLink replay errors across all lanes |
+    | `NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_TOTAL` | NvLink recovery errors across all lanes |
+    | `NVML_FI_DEV_ECC_SBE_VOL_TOTAL` | Volatile single bit ECC errors |
+    | `NVML_FI_DEV_ECC_DBE_VOL_TOTAL` | Volatile double bit ECC errors |
+    | `NVML_FI_DEV_ECC_SBE_AGG_TOTAL` | Aggregate (persistent) single bit ECC errors |
+    | `NVML_FI_DEV_ECC_DBE_AGG_TOTAL` | Aggregate (persistent) double bit ECC errors |
+    | `NVML_FI_DEV_MEMORY_TEMP` | Memory temperature in degrees C |
+    | `NVML_FI_DEV_RETIRED_SBE` | Pages retired due to single bit errors |
+    | `NVML_FI_DEV_RETIRED_DBE` | Pages retired due to double bit errors |
+    | `NVML_FI_DEV_RETIRED_PENDING` | 1 if any pages are pending retirement, 0 otherwise |
+
+    See the NVML header for the full list.
+    */
     pub mod field_id {
         pub use crate::ffi::bindings::field_id::*;
     }
@@ -143,14 +179,18 @@ use static_assertions::assert_impl_all;
 #[cfg(target_os = "linux")]
 use crate::enum_wrappers::device::TopologyLevel;
 
+use crate::enums::nv_link::NvLinkBwMode;
 use crate::error::{nvml_sym, nvml_try, NvmlError};
 use crate::ffi::bindings::*;
 
 use crate::struct_wrappers::ExcludedDeviceInfo;
+use crate::structs::CudaDriverVersion;
 
 #[cfg(target_os = "linux")]
 use crate::struct_wrappers::device::PciInfo;
-use crate::struct_wrappers::device::VgpuVersion;
+use crate::struct_wrappers::device::{
+    VgpuMetadata, VgpuPgpuCompatibility, VgpuPgpuMetadata, VgpuVersion,
+};
 use crate::struct_wrappers::unit::HwbcEntry;
 
 use crate::bitmasks::InitFlags;
@@ -264,6 +304,11 @@ impl Nvml {
     dynamically loading function symbols from the `NVML` lib and are therefore
     somewhat expensive.
 
+    Because of that reference counting, independent `Nvml` instances don't
+    interfere with each other: each one shuts down only its own initialization
+    when dropped, and NVML is only actually shut down once the last live
+    instance is gone.
+
     Note that this will initialize NVML but not any GPUs. This means that NVML can
     communicate with a GPU even when other GPUs in a system are bad or unstable.
 
@@ -286,7 +331,7 @@ impl Nvml {
     fn init_internal(path: impl AsRef<std::ffi::OsStr>) -> Result<Self, NvmlError> {
         let lib = unsafe {
             let lib = NvmlLib::new(path)?;
-            let sym = nvml_sym(lib.nvmlInit_v2.as_ref())?;
+            let sym = nvml_sym(lib.nvmlInit_v2.as_ref(), "nvmlInit_v2")?;
 
             nvml_try(sym())?;
             ManuallyDrop::new(lib)
@@ -342,7 +387,7 @@ impl Nvml {
     ) -> Result<Self, NvmlError> {
         let lib = unsafe {
             let lib = NvmlLib::new(path)?;
-            let sym = nvml_sym(lib.nvmlInitWithFlags.as_ref())?;
+            let sym = nvml_sym(lib.nvmlInitWithFlags.as_ref(), "nvmlInitWithFlags")?;
 
             nvml_try(sym(flags.bits()))?;
             ManuallyDrop::new(lib)
@@ -379,6 +424,9 @@ impl Nvml {
     Use this to shutdown NVML and release allocated resources if you care about handling
     potential errors (*the `Drop` implementation ignores errors!*).
 
+    `nvmlShutdown` is called exactly once for this `Nvml`, even if it fails; the
+    `Drop` implementation does not call it again afterwards.
+
     # Errors
 
     * `Uninitialized`, if the library has not been successfully initialized
@@ -389,24 +437,32 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlShutdown")]
     pub fn shutdown(mut self) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlShutdown.as_ref())?;
-
-        unsafe {
-            nvml_try(sym())?;
-        }
-
         // SAFETY: we `mem::forget(self)` after this, so `self.lib` won't get
         // touched by our `Drop` impl
+        //
+        // This happens before calling `nvmlShutdown` so that our `Drop` impl
+        // can't call it a second time if it fails. NVML reference counts
+        // initialization, so an extra shutdown would tear it down from under
+        // other `Nvml` instances.
         let lib = unsafe { ManuallyDrop::take(&mut self.lib) };
         mem::forget(self);
 
+        let sym = nvml_sym(lib.nvmlShutdown.as_ref(), "nvmlShutdown")?;
+
+        unsafe {
+            nvml_try(sym())?;
+        }
+
         Ok(lib.__library.close()?)
     }
 
     /**
     Get the number of compute devices in the system (compute device == one GPU).
 
-    Note that this count can include devices you do not have permission to access.
+    Note that this count can include devices you do not have permission to access;
+    `.device_by_index()` returns `NoPermission` for those. With the
+    `legacy-functions` feature enabled, `.accessible_device_count()` counts only
+    the devices you can access.
 
     # Errors
 
@@ -417,7 +473,40 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlDeviceGetCount_v2")]
     pub fn device_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlDeviceGetCount_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceGetCount_v2.as_ref(),
+            "nvmlDeviceGetCount_v2",
+        )?;
+
+        unsafe {
+            let mut count: c_uint = mem::zeroed();
+            nvml_try(sym(&mut count))?;
+
+            Ok(count)
+        }
+    }
+
+    /**
+    Get the number of compute devices in the system that NVML has permission to
+    access.
+
+    This uses the original `nvmlDeviceGetCount` API, which NVML keeps around for
+    backwards compatibility. Unlike `.device_count()`, it skips devices you
+    don't have permission to access, which is common in containers that only
+    expose some of a host's GPUs.
+
+    Note that the count is not a range of valid indices for
+    `.device_by_index()`, since inaccessible devices still occupy an index.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlDeviceGetCount")]
+    #[cfg(feature = "legacy-functions")]
+    pub fn accessible_device_count(&self) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(self.lib.nvmlDeviceGetCount.as_ref(), "nvmlDeviceGetCount")?;
 
         unsafe {
             let mut count: c_uint = mem::zeroed();
@@ -440,7 +529,10 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlSystemGetDriverVersion")]
     pub fn sys_driver_version(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetDriverVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetDriverVersion.as_ref(),
+            "nvmlSystemGetDriverVersion",
+        )?;
 
         unsafe {
             let mut version_vec = vec![0; NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE as usize];
@@ -467,7 +559,10 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlSystemGetNVMLVersion")]
     pub fn sys_nvml_version(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetNVMLVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetNVMLVersion.as_ref(),
+            "nvmlSystemGetNVMLVersion",
+        )?;
 
         unsafe {
             let mut version_vec = vec![0; NVML_SYSTEM_NVML_VERSION_BUFFER_SIZE as usize];
@@ -498,7 +593,10 @@ impl Nvml {
     */
     #[doc(alias = "nvmlSystemGetCudaDriverVersion_v2")]
     pub fn sys_cuda_driver_version(&self) -> Result<i32, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetCudaDriverVersion_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetCudaDriverVersion_v2.as_ref(),
+            "nvmlSystemGetCudaDriverVersion_v2",
+        )?;
 
         unsafe {
             let mut version: c_int = mem::zeroed();
@@ -508,6 +606,57 @@ impl Nvml {
         }
     }
 
+    /**
+    Gets the version of the system's CUDA driver, split into its major and minor
+    parts.
+
+    This is a convenience wrapper around `.sys_cuda_driver_version()`.
+
+    # Errors
+
+    * `FunctionNotFound`, if cuDriverGetVersion() is not found in the shared library
+    * `LibraryNotFound`, if libcuda.so.1 or libcuda.dll cannot be found
+    */
+    #[doc(alias = "nvmlSystemGetCudaDriverVersion_v2")]
+    pub fn cuda_driver_version(&self) -> Result<CudaDriverVersion, NvmlError> {
+        Ok(self.sys_cuda_driver_version()?.into())
+    }
+
+    /**
+    Gets the branch of the system's graphics driver and returns it as an
+    alphanumeric string.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `Utf8Error`, if the string obtained from the C function is not valid Utf8
+    * `Unknown`, on any unexpected error
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlSystemGetDriverBranch")]
+    pub fn sys_driver_branch(&self) -> Result<String, NvmlError> {
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetDriverBranch.as_ref(),
+            "nvmlSystemGetDriverBranch",
+        )?;
+
+        unsafe {
+            let mut branch_info: nvmlSystemDriverBranchInfo_t = mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(SystemDriverBranchInfo, 1), as detailed in nvml.h
+            branch_info.version =
+                (mem::size_of::<nvmlSystemDriverBranchInfo_v1_t>() | (1_usize << 24_usize)) as u32;
+
+            nvml_try(sym(
+                &mut branch_info,
+                NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE,
+            ))?;
+
+            let branch_raw = CStr::from_ptr(branch_info.branch.as_ptr());
+            Ok(branch_raw.to_str()?.into())
+        }
+    }
+
     /**
     Gets the name of the process for the given process ID, cropped to the provided length.
 
@@ -527,7 +676,10 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlSystemGetProcessName")]
     pub fn sys_process_name(&self, pid: u32, length: usize) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetProcessName.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetProcessName.as_ref(),
+            "nvmlSystemGetProcessName",
+        )?;
 
         unsafe {
             let mut name_vec = vec![0; length];
@@ -539,6 +691,25 @@ impl Nvml {
         }
     }
 
+    /**
+    Gets the name of the process for the given process ID.
+
+    This is `.sys_process_name()` with a buffer large enough for typical
+    executable paths; names longer than 1023 bytes are cropped.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `NotFound`, if the process does not exist
+    * `NoPermission`, if the user doesn't have permission to perform the operation
+    * `Utf8Error`, if the string obtained from the C function is not valid UTF-8
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlSystemGetProcessName")]
+    pub fn process_name(&self, pid: u32) -> Result<String, NvmlError> {
+        self.sys_process_name(pid, 1024)
+    }
+
     /**
     Acquire the handle for a particular device based on its index (starts at 0).
 
@@ -572,7 +743,10 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlDeviceGetHandleByIndex_v2")]
     pub fn device_by_index(&self, index: u32) -> Result<Device<'_>, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlDeviceGetHandleByIndex_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceGetHandleByIndex_v2.as_ref(),
+            "nvmlDeviceGetHandleByIndex_v2",
+        )?;
 
         unsafe {
             let mut device: nvmlDevice_t = mem::zeroed();
@@ -612,7 +786,10 @@ impl Nvml {
     where
         Vec<u8>: From<S>,
     {
-        let sym = nvml_sym(self.lib.nvmlDeviceGetHandleByPciBusId_v2.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceGetHandleByPciBusId_v2.as_ref(),
+            "nvmlDeviceGetHandleByPciBusId_v2",
+        )?;
 
         unsafe {
             let c_string = CString::new(pci_bus_id)?;
@@ -633,7 +810,10 @@ impl Nvml {
     where
         Vec<u8>: From<S>,
     {
-        let sym = nvml_sym(self.lib.nvmlDeviceGetHandleBySerial.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceGetHandleBySerial.as_ref(),
+            "nvmlDeviceGetHandleBySerial",
+        )?;
 
         unsafe {
             let c_string = CString::new(board_serial)?;
@@ -672,7 +852,10 @@ impl Nvml {
     where
         Vec<u8>: From<S>,
     {
-        let sym = nvml_sym(self.lib.nvmlDeviceGetHandleByUUID.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceGetHandleByUUID.as_ref(),
+            "nvmlDeviceGetHandleByUUID",
+        )?;
 
         unsafe {
             let c_string = CString::new(uuid)?;
@@ -709,7 +892,10 @@ impl Nvml {
         device1: &Device,
         device2: &Device,
     ) -> Result<TopologyLevel, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlDeviceGetTopologyCommonAncestor.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceGetTopologyCommonAncestor.as_ref(),
+            "nvmlDeviceGetTopologyCommonAncestor",
+        )?;
 
         unsafe {
             let mut level: nvmlGpuTopologyLevel_t = mem::zeroed();
@@ -744,7 +930,10 @@ impl Nvml {
     // Tested (for an error)
     #[doc(alias = "nvmlUnitGetHandleByIndex")]
     pub fn unit_by_index(&self, index: u32) -> Result<Unit<'_>, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlUnitGetHandleByIndex.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlUnitGetHandleByIndex.as_ref(),
+            "nvmlUnitGetHandleByIndex",
+        )?;
 
         unsafe {
             let mut unit: nvmlUnit_t = mem::zeroed();
@@ -775,7 +964,10 @@ impl Nvml {
         device1: &Device,
         device2: &Device,
     ) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlDeviceOnSameBoard.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceOnSameBoard.as_ref(),
+            "nvmlDeviceOnSameBoard",
+        )?;
 
         unsafe {
             let mut bool_int: c_int = mem::zeroed();
@@ -806,7 +998,10 @@ impl Nvml {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlSystemGetTopologyGpuSet")]
     pub fn topology_gpu_set(&self, cpu_number: u32) -> Result<Vec<Device<'_>>, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetTopologyGpuSet.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetTopologyGpuSet.as_ref(),
+            "nvmlSystemGetTopologyGpuSet",
+        )?;
 
         unsafe {
             let mut count = match self.topology_gpu_set_count(cpu_number)? {
@@ -824,7 +1019,10 @@ impl Nvml {
     // Helper function for the above.
     #[cfg(target_os = "linux")]
     fn topology_gpu_set_count(&self, cpu_number: u32) -> Result<c_uint, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetTopologyGpuSet.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetTopologyGpuSet.as_ref(),
+            "nvmlSystemGetTopologyGpuSet",
+        )?;
 
         unsafe {
             // Indicates that we want the count
@@ -852,7 +1050,10 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlSystemGetHicVersion")]
     pub fn hic_versions(&self) -> Result<Vec<HwbcEntry>, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetHicVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetHicVersion.as_ref(),
+            "nvmlSystemGetHicVersion",
+        )?;
 
         unsafe {
             let mut count: c_uint = match self.hic_count()? {
@@ -881,7 +1082,10 @@ impl Nvml {
     // Tested as part of the above method
     #[doc(alias = "nvmlSystemGetHicVersion")]
     pub fn hic_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSystemGetHicVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetHicVersion.as_ref(),
+            "nvmlSystemGetHicVersion",
+        )?;
 
         unsafe {
             /*
@@ -926,7 +1130,7 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlUnitGetCount")]
     pub fn unit_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlUnitGetCount.as_ref())?;
+        let sym = nvml_sym(self.lib.nvmlUnitGetCount.as_ref(), "nvmlUnitGetCount")?;
 
         unsafe {
             let mut count: c_uint = mem::zeroed();
@@ -952,7 +1156,7 @@ impl Nvml {
     // Tested
     #[doc(alias = "nvmlEventSetCreate")]
     pub fn create_event_set(&self) -> Result<EventSet<'_>, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlEventSetCreate.as_ref())?;
+        let sym = nvml_sym(self.lib.nvmlEventSetCreate.as_ref(), "nvmlEventSetCreate")?;
 
         unsafe {
             let mut set: nvmlEventSet_t = mem::zeroed();
@@ -1007,7 +1211,10 @@ impl Nvml {
     #[cfg(target_os = "linux")]
     #[doc(alias = "nvmlDeviceDiscoverGpus")]
     pub fn discover_gpus(&self, pci_info: PciInfo) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlDeviceDiscoverGpus.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlDeviceDiscoverGpus.as_ref(),
+            "nvmlDeviceDiscoverGpus",
+        )?;
 
         unsafe { nvml_try(sym(&mut pci_info.try_into()?)) }
     }
@@ -1021,7 +1228,10 @@ impl Nvml {
     */
     #[doc(alias = "nvmlGetExcludedDeviceCount")]
     pub fn excluded_device_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlGetExcludedDeviceCount.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlGetExcludedDeviceCount.as_ref(),
+            "nvmlGetExcludedDeviceCount",
+        )?;
 
         unsafe {
             let mut count: c_uint = mem::zeroed();
@@ -1045,7 +1255,10 @@ impl Nvml {
     */
     #[doc(alias = "nvmlGetExcludedDeviceInfoByIndex")]
     pub fn excluded_device_info(&self, index: u32) -> Result<ExcludedDeviceInfo, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlGetExcludedDeviceInfoByIndex.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlGetExcludedDeviceInfoByIndex.as_ref(),
+            "nvmlGetExcludedDeviceInfoByIndex",
+        )?;
 
         unsafe {
             let mut info: nvmlExcludedDeviceInfo_t = mem::zeroed();
@@ -1072,7 +1285,10 @@ impl Nvml {
         &self,
         capability: nvmlVgpuDriverCapability_t,
     ) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlGetVgpuDriverCapabilities.as_ref())?;
+        let sym = nvml_sym(
+            self.lib.nvmlGetVgpuDriverCapabilities.as_ref(),
+            "nvmlGetVgpuDriverCapabilities",
+        )?;
 
         unsafe {
             let mut mask: u32 = mem::zeroed();
@@ -1094,7 +1310,7 @@ impl Nvml {
     */
     #[doc(alias = "nvmlGetVgpuVersion")]
     pub fn vgpu_version(&self) -> Result<(VgpuVersion, VgpuVersion), NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlGetVgpuVersion.as_ref())?;
+        let sym = nvml_sym(self.lib.nvmlGetVgpuVersion.as_ref(), "nvmlGetVgpuVersion")?;
 
         unsafe {
             let mut supported: nvmlVgpuVersion_t = mem::zeroed();
@@ -1107,10 +1323,119 @@ impl Nvml {
 
     #[doc(alias = "nvmlSetVgpuVersion")]
     pub fn set_vgpu_version(&self, version: VgpuVersion) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.lib.nvmlSetVgpuVersion.as_ref())?;
+        let sym = nvml_sym(self.lib.nvmlSetVgpuVersion.as_ref(), "nvmlSetVgpuVersion")?;
 
         unsafe { nvml_try(sym(&mut version.as_c())) }
     }
+
+    /**
+    Gets the compatibility of a vGPU instance with a physical GPU.
+
+    `vgpu` is obtained from `Device.vgpu_instance_metadata()` (or rebuilt
+    from persisted bytes with `VgpuMetadata::try_from_raw()`), and `pgpu` from
+    `Device.vgpu_metadata()` on the target GPU. The returned struct describes
+    the states in which the vGPU / VM may be booted on the physical GPU.
+
+    Note that this does not take into account dynamic capacity conditions that
+    may limit a system's ability to boot a given vGPU or associated VM.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if either metadata buffer is invalid
+    * `IncorrectBits`, if NVML returns a bitmask this wrapper does not recognize
+    * `Unknown`, on any unexpected error
+    */
+    #[doc(alias = "nvmlGetVgpuCompatibility")]
+    pub fn vgpu_compatibility(
+        &self,
+        vgpu: &VgpuMetadata,
+        pgpu: &VgpuPgpuMetadata,
+    ) -> Result<VgpuPgpuCompatibility, NvmlError> {
+        let sym = nvml_sym(
+            self.lib.nvmlGetVgpuCompatibility.as_ref(),
+            "nvmlGetVgpuCompatibility",
+        )?;
+
+        let mut vgpu_buffer = vgpu.as_c_buffer();
+        let mut pgpu_buffer = pgpu.as_c_buffer();
+
+        unsafe {
+            let mut compatibility: nvmlVgpuPgpuCompatibility_t = mem::zeroed();
+
+            nvml_try(sym(
+                vgpu_buffer.as_mut_ptr() as *mut nvmlVgpuMetadata_t,
+                pgpu_buffer.as_mut_ptr() as *mut nvmlVgpuPgpuMetadata_t,
+                &mut compatibility,
+            ))?;
+
+            VgpuPgpuCompatibility::try_from(compatibility)
+        }
+    }
+
+    /**
+    Gets the global NvLink bandwidth mode.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `NotSupported`, if no GPU in the system is Hopper or newer
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Hopper or newer fully supported devices.
+    */
+    // Checked against local
+    // Tested
+    #[doc(alias = "nvmlSystemGetNvlinkBwMode")]
+    pub fn nvlink_bw_mode(&self) -> Result<NvLinkBwMode, NvmlError> {
+        let sym = nvml_sym(
+            self.lib.nvmlSystemGetNvlinkBwMode.as_ref(),
+            "nvmlSystemGetNvlinkBwMode",
+        )?;
+
+        unsafe {
+            let mut mode: c_uint = mem::zeroed();
+            nvml_try(sym(&mut mode))?;
+
+            NvLinkBwMode::try_from(mode)
+        }
+    }
+
+    /**
+    Sets the global NvLink bandwidth mode.
+
+    Lowering the bandwidth mode can be used to reduce NvLink power draw.
+
+    Requires root/admin permissions.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if `mode` is invalid (shouldn't occur?)
+    * `InUse`, if peer-to-peer objects currently exist
+    * `NotSupported`, if no GPU in the system is Hopper or newer
+    * `NoPermission`, if the user doesn't have permission to perform this operation
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Hopper or newer fully supported devices.
+    */
+    // Checked against local
+    // Tested (no-run)
+    #[doc(alias = "nvmlSystemSetNvlinkBwMode")]
+    pub fn set_nvlink_bw_mode(&self, mode: NvLinkBwMode) -> Result<(), NvmlError> {
+        let sym = nvml_sym(
+            self.lib.nvmlSystemSetNvlinkBwMode.as_ref(),
+            "nvmlSystemSetNvlinkBwMode",
+        )?;
+
+        unsafe { nvml_try(sym(mode.as_c())) }
+    }
 }
 
 /// This `Drop` implementation ignores errors! Use the `.shutdown()` method on
@@ -1196,6 +1521,266 @@ mod test {
     use crate::error::NvmlError;
     use crate::test_utils::*;
 
+    // Every public data struct should be (de)serializable with the `serde`
+    // feature enabled. `FieldValueSample` and `GpuFabricInfo` are the
+    // exceptions since they hold a `Result<_, NvmlError>`.
+    #[cfg(feature = "serde")]
+    macro_rules! assert_serde {
+        ($($t:ty,)*) => {
+            $(static_assertions::assert_impl_all!(
+                $t: serde::Serialize, serde::de::DeserializeOwned
+            );)*
+        };
+    }
+
+    #[cfg(all(feature = "serde", target_os = "windows"))]
+    assert_serde!(
+        crate::structs::device::DriverModelState,
+        crate::enums::device::DriverModelTransition,
+    );
+
+    #[cfg(feature = "serde")]
+    assert_serde!(
+        crate::struct_wrappers::device::PciInfo,
+        crate::struct_wrappers::device::PciInfoExt,
+        crate::struct_wrappers::device::BAR1MemoryInfo,
+        crate::struct_wrappers::device::BridgeChipInfo,
+        crate::struct_wrappers::device::BridgeChipHierarchy,
+        crate::struct_wrappers::device::ProcessInfo,
+        crate::struct_wrappers::device::EccErrorCounts,
+        crate::struct_wrappers::device::MemoryInfo,
+        crate::struct_wrappers::device::Utilization,
+        crate::struct_wrappers::device::ViolationTime,
+        crate::struct_wrappers::device::ThermalSensor,
+        crate::struct_wrappers::device::AccountingStats,
+        crate::struct_wrappers::device::EncoderSessionInfo,
+        crate::struct_wrappers::device::Sample,
+        crate::struct_wrappers::device::ProcessUtilizationSample,
+        crate::struct_wrappers::device::FbcStats,
+        crate::struct_wrappers::device::FbcSessionInfo,
+        crate::struct_wrappers::device::DeviceAttributes,
+        crate::struct_wrappers::device::DomainUtilization,
+        crate::struct_wrappers::device::DynamicPstatesInfo,
+        crate::struct_wrappers::device::C2cModeInfo,
+        crate::struct_wrappers::device::FanSpeedInfo,
+        crate::struct_wrappers::device::ClockOffset,
+        crate::struct_wrappers::device::ProfileInfo,
+        crate::struct_wrappers::device::GpuInstancePlacement,
+        crate::struct_wrappers::device::ComputeInstanceProfileInfo,
+        crate::struct_wrappers::device::GpuInstanceInfo,
+        crate::struct_wrappers::device::ComputeInstancePlacement,
+        crate::struct_wrappers::device::ComputeInstanceInfo,
+        crate::struct_wrappers::device::VgpuSchedulerCapabilities,
+        crate::struct_wrappers::device::VgpuVersion,
+        crate::struct_wrappers::device::VgpuInstanceUtilizationInfo,
+        crate::struct_wrappers::device::VgpuPlacementList,
+        crate::struct_wrappers::device::VgpuMetadata,
+        crate::struct_wrappers::device::VgpuPgpuMetadata,
+        crate::struct_wrappers::device::VgpuPgpuCompatibility,
+        crate::struct_wrappers::device::LicenseExpiry,
+        crate::struct_wrappers::device::GridLicensableFeature,
+        crate::struct_wrappers::device::GridLicensableFeatures,
+        crate::struct_wrappers::device::VgpuLicenseInfo,
+        crate::struct_wrappers::device::VgpuSchedulerParams,
+        crate::struct_wrappers::device::VgpuSchedulerLogEntry,
+        crate::struct_wrappers::device::VgpuSchedulerLog,
+        crate::struct_wrappers::device::VgpuSchedulerGetState,
+        crate::struct_wrappers::device::VgpuSchedulerSetParams,
+        crate::struct_wrappers::device::VgpuSchedulerSetState,
+        crate::struct_wrappers::gpm::GpmMetricInfo,
+        crate::struct_wrappers::gpm::GpmMetricResult,
+        crate::struct_wrappers::nv_link::UtilizationControl,
+        crate::struct_wrappers::unit::FansInfo,
+        crate::struct_wrappers::unit::FanInfo,
+        crate::struct_wrappers::unit::PsuInfo,
+        crate::struct_wrappers::unit::UnitInfo,
+        crate::struct_wrappers::unit::HwbcEntry,
+        crate::struct_wrappers::ExcludedDeviceInfo,
+        crate::structs::attestation::SpdmAttestationReport,
+        crate::structs::nv_link::NvLinkThroughput,
+        crate::structs::nv_link::LinkErrors,
+        crate::structs::attestation::SpdmMeasurementBlock,
+        crate::structs::device::ConfidentialComputeCapabilities,
+        crate::structs::device::ConfidentialComputeState,
+        crate::structs::device::CurrentClocks,
+        crate::structs::device::PowerSample,
+        crate::structs::device::PerformanceSnapshot,
+        crate::structs::device::HealthSnapshot,
+        crate::structs::device::RemappedRows,
+        crate::structs::device::MemoryReport,
+        crate::structs::device::ThrottlingReport,
+        crate::structs::device::PcieUtilization,
+        crate::structs::device::ConfidentialComputeGpuAttestationReport,
+        crate::structs::device::ConfidentialComputeGpuCertificate,
+        crate::structs::device::AutoBoostClocksEnabledInfo,
+        crate::structs::device::UtilizationInfo,
+        crate::structs::device::EccModeState,
+        crate::structs::device::OperationModeState,
+        crate::structs::device::PowerManagementConstraints,
+        crate::structs::device::EncoderStats,
+        crate::structs::device::CudaComputeCapability,
+        crate::structs::device::RetiredPage,
+        crate::structs::device::FieldId,
+        crate::structs::device::VbiosVersion,
+        crate::structs::device::MigMode,
+        crate::structs::device::GspFirmwareMode,
+        crate::structs::nv_link::UtilizationCounter,
+        crate::structs::CudaDriverVersion,
+        crate::high_level::snapshot::DeviceMetrics,
+    );
+
+    #[cfg(feature = "serde")]
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let json = serde_json::to_string(value).expect("serialized");
+        serde_json::from_str(&json).expect("deserialized")
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_struct() {
+        use crate::struct_wrappers::device::MemoryInfo;
+
+        let info = MemoryInfo {
+            free: 1 << 30,
+            reserved: 1 << 20,
+            total: 1 << 34,
+            used: (1 << 34) - (1 << 30) - (1 << 20),
+            version: 2,
+        };
+
+        assert_eq!(round_trip(&info), info);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_gpu_certificate() {
+        use crate::ffi::bindings::{
+            NVML_GPU_ATTESTATION_CERT_CHAIN_SIZE, NVML_GPU_CERT_CHAIN_SIZE,
+        };
+        use crate::structs::device::ConfidentialComputeGpuCertificate;
+
+        let cert = ConfidentialComputeGpuCertificate {
+            cert_chain_size: NVML_GPU_CERT_CHAIN_SIZE,
+            attestation_cert_chain_size: NVML_GPU_ATTESTATION_CERT_CHAIN_SIZE,
+            cert_chain: (0..NVML_GPU_CERT_CHAIN_SIZE).map(|i| i as u8).collect(),
+            attestation_cert_chain: (0..NVML_GPU_ATTESTATION_CERT_CHAIN_SIZE)
+                .map(|i| !i as u8)
+                .collect(),
+        };
+
+        assert_eq!(round_trip(&cert), cert);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_enum() {
+        use crate::enum_wrappers::device::PerformanceState;
+        use crate::enums::device::SampleValue;
+
+        assert_eq!(round_trip(&PerformanceState::Two), PerformanceState::Two);
+        assert_eq!(round_trip(&SampleValue::I64(-5)), SampleValue::I64(-5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_pci_info() {
+        use crate::struct_wrappers::device::PciInfo;
+
+        let mut info = PciInfo {
+            bus: 0x41,
+            bus_id: "00000000:41:00.0".into(),
+            device: 0,
+            domain: 0,
+            pci_device_id: 0x2204_10de,
+            pci_sub_system_id: Some(0x1454_10de),
+        };
+        assert_eq!(round_trip(&info), info);
+
+        info.pci_sub_system_id = None;
+        assert_eq!(round_trip(&info), info);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_utilization() {
+        use crate::struct_wrappers::device::Utilization;
+
+        let utilization = Utilization {
+            gpu: 87,
+            memory: 42,
+        };
+
+        assert_eq!(round_trip(&utilization), utilization);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_confidential_compute() {
+        use crate::ffi::bindings::{
+            NVML_CC_GPU_ATTESTATION_REPORT_SIZE, NVML_CC_GPU_CEC_ATTESTATION_REPORT_SIZE,
+        };
+        use crate::structs::device::*;
+
+        let caps = ConfidentialComputeCapabilities {
+            cpu_caps: ConfidentialComputeCpuCapabilities::AmdSev,
+            gpus_caps: ConfidentialComputeGpuCapabilities::Capable,
+        };
+        assert_eq!(round_trip(&caps), caps);
+
+        let state = ConfidentialComputeState {
+            environment: ConfidentialComputeEnvironment::Prod,
+            cc_feature: ConfidentialComputeFeature::Enabled,
+            dev_tools_mode: ConfidentialComputeDevToolsMode::Off,
+        };
+        assert_eq!(round_trip(&state), state);
+
+        assert_eq!(
+            round_trip(&ConfidentialComputeReadyState::Ready),
+            ConfidentialComputeReadyState::Ready
+        );
+
+        let threshold = ConfidentialComputeKeyRotationThreshold {
+            max_attacker_advantage: 60,
+        };
+        assert_eq!(round_trip(&threshold), threshold);
+
+        let report = ConfidentialComputeGpuAttestationReport {
+            attestation_report_size: NVML_CC_GPU_ATTESTATION_REPORT_SIZE,
+            attestation_report: vec![0xab; NVML_CC_GPU_ATTESTATION_REPORT_SIZE as usize],
+            is_cec_attestation_report_present: true,
+            cec_attestation_report_size: NVML_CC_GPU_CEC_ATTESTATION_REPORT_SIZE,
+            cec_attestation_report: vec![0xcd; NVML_CC_GPU_CEC_ATTESTATION_REPORT_SIZE as usize],
+        };
+        assert_eq!(round_trip(&report), report);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_unknown_variants() {
+        use crate::structs::device::*;
+
+        let caps = ConfidentialComputeCapabilities {
+            cpu_caps: ConfidentialComputeCpuCapabilities::Unknown(7),
+            gpus_caps: ConfidentialComputeGpuCapabilities::Unknown(8),
+        };
+        assert_eq!(round_trip(&caps), caps);
+
+        let state = ConfidentialComputeState {
+            environment: ConfidentialComputeEnvironment::Unknown(9),
+            cc_feature: ConfidentialComputeFeature::Unknown(10),
+            dev_tools_mode: ConfidentialComputeDevToolsMode::Unknown(u32::MAX),
+        };
+        assert_eq!(round_trip(&state), state);
+
+        assert_eq!(
+            round_trip(&ConfidentialComputeReadyState::Unknown(2)),
+            ConfidentialComputeReadyState::Unknown(2)
+        );
+    }
+
     #[test]
     fn init_with_flags() {
         Nvml::init_with_flags(InitFlags::NO_GPUS).unwrap();
@@ -1206,11 +1791,28 @@ mod test {
         test(3, || nvml().shutdown())
     }
 
+    #[test]
+    fn shutdown_leaves_other_instances_usable() {
+        test(3, || {
+            let first = nvml();
+            let second = nvml();
+
+            drop(first);
+            second.device_count()
+        })
+    }
+
     #[test]
     fn device_count() {
         test(3, || nvml().device_count())
     }
 
+    #[cfg(feature = "legacy-functions")]
+    #[cfg_attr(feature = "legacy-functions", test)]
+    fn accessible_device_count() {
+        test(3, || nvml().accessible_device_count())
+    }
+
     #[test]
     fn sys_driver_version() {
         test(3, || nvml().sys_driver_version())
@@ -1240,6 +1842,39 @@ mod test {
         })
     }
 
+    #[test]
+    fn cuda_driver_version() {
+        test(3, || nvml().cuda_driver_version())
+    }
+
+    #[test]
+    fn sys_driver_branch() {
+        test(3, || nvml().sys_driver_branch())
+    }
+
+    #[test]
+    fn cuda_driver_version_from_full_version() {
+        assert_eq!(
+            CudaDriverVersion::from(12030),
+            CudaDriverVersion {
+                major: 12,
+                minor: 3
+            }
+        );
+    }
+
+    #[test]
+    fn process_name() {
+        let nvml = nvml();
+        test_with_device(3, &nvml, |device| {
+            let processes = device.running_compute_processes()?;
+            match processes.first() {
+                Some(process) => nvml.process_name(process.pid),
+                None => Ok("No running processes".into()),
+            }
+        })
+    }
+
     #[test]
     fn sys_process_name() {
         let nvml = nvml();
@@ -1396,6 +2031,50 @@ mod test {
         test(3, || nvml.set_vgpu_version(VgpuVersion { min: 0, max: 0 }))
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn vgpu_compatibility() {
+        let nvml = nvml();
+        test(3, || {
+            let device = device(&nvml);
+            let pgpu = device.vgpu_metadata()?;
+            let vgpu =
+                device.vgpu_instance_metadata(*device.active_vgpus()?.first().unwrap_or(&0))?;
+
+            nvml.vgpu_compatibility(&vgpu, &pgpu)
+        })
+    }
+
+    #[test]
+    fn snapshot_all() {
+        use crate::high_level::Metric;
+
+        let nvml = nvml();
+        test(3, || {
+            nvml.snapshot_all(&[
+                Metric::Temperature,
+                Metric::Power,
+                Metric::Utilization,
+                Metric::MemoryUsed,
+                Metric::FanSpeed,
+                Metric::Clocks,
+            ])
+        })
+    }
+
+    #[test]
+    fn nvlink_bw_mode() {
+        let nvml = nvml();
+        test(3, || nvml.nvlink_bw_mode())
+    }
+
+    // This modifies global state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn set_nvlink_bw_mode() {
+        let nvml = nvml();
+        nvml.set_nvlink_bw_mode(NvLinkBwMode::Full).unwrap();
+    }
+
     #[test]
     fn detect_field_id_scheme_v12_drivers() {
         assert_eq!(detect_field_id_scheme("575.51.03"), FieldIdScheme::V12);
@@ -1505,4 +2184,74 @@ mod test {
         assert_eq!(translate_field_id(FieldIdScheme::V13Update1, 250), 250);
         assert_eq!(translate_field_id(FieldIdScheme::V13Update1, 274), 274);
     }
+
+    #[test]
+    fn formatted_bus_id() {
+        use crate::enums::device::BusIdFormat;
+        use crate::struct_wrappers::device::PciInfo;
+
+        let info = PciInfo {
+            bus: 0x3b,
+            bus_id: "00000000:3B:00.1".into(),
+            device: 0,
+            domain: 0,
+            pci_device_id: 0,
+            pci_sub_system_id: None,
+        };
+
+        assert_eq!(info.formatted_bus_id(BusIdFormat::Legacy), "0000:3B:00.1");
+        assert_eq!(
+            info.formatted_bus_id(BusIdFormat::Extended),
+            "00000000:3B:00.1"
+        );
+    }
+
+    #[test]
+    fn sample_value_from_tag_and_union() {
+        use crate::enum_wrappers::device::SampleValueType;
+        use crate::enums::device::SampleValue;
+        use crate::ffi::bindings::nvmlValue_t;
+
+        let decode = |tag, union| SampleValue::from_tag_and_union(&tag, union);
+
+        assert_eq!(
+            decode(SampleValueType::Double, nvmlValue_t { dVal: 1.5 }),
+            SampleValue::F64(1.5)
+        );
+        assert_eq!(
+            decode(
+                SampleValueType::UnsignedLongLong,
+                nvmlValue_t { ullVal: u64::MAX }
+            ),
+            SampleValue::U64(u64::MAX)
+        );
+        assert_eq!(
+            decode(SampleValueType::SignedLongLong, nvmlValue_t { sllVal: -2 }),
+            SampleValue::I64(-2)
+        );
+        assert_eq!(
+            decode(SampleValueType::SignedInt, nvmlValue_t { siVal: -3 }),
+            SampleValue::I32(-3)
+        );
+        assert_eq!(
+            decode(SampleValueType::UnsignedShort, nvmlValue_t { usVal: 7 }),
+            SampleValue::U16(7)
+        );
+    }
+
+    #[test]
+    fn field_id_name() {
+        use crate::ffi::bindings::field_id::*;
+        use crate::structs::device::FieldId;
+
+        assert_eq!(
+            FieldId(NVML_FI_DEV_POWER_INSTANT).name(),
+            "NVML_FI_DEV_POWER_INSTANT"
+        );
+        assert_eq!(
+            FieldId(NVML_FI_DEV_CLOCKS_EVENT_REASON_SW_POWER_CAP).name(),
+            "NVML_FI_DEV_PERF_POLICY_POWER"
+        );
+        assert_eq!(FieldId(NVML_FI_MAX).name(), "unknown");
+    }
 }
@@ -1,8 +1,9 @@
 use crate::Device;
 
+use crate::bitmasks::nv_link::PacketTypes;
 use crate::enum_wrappers::{
     bool_from_state,
-    nv_link::{Capability, ErrorCounter, IntDeviceType},
+    nv_link::{Capability, ErrorCounter, IntDeviceType, UtilizationCountUnit},
     state_from_bool,
 };
 
@@ -86,7 +87,10 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
     // Test written
     #[doc(alias = "nvmlDeviceGetNvLinkState")]
     pub fn is_active(&self) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlDeviceGetNvLinkState.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlDeviceGetNvLinkState.as_ref(),
+            "nvmlDeviceGetNvLinkState",
+        )?;
 
         unsafe {
             let mut state: nvmlEnableState_t = mem::zeroed();
@@ -115,7 +119,10 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
     // Test written
     #[doc(alias = "nvmlDeviceGetNvLinkVersion")]
     pub fn version(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlDeviceGetNvLinkVersion.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlDeviceGetNvLinkVersion.as_ref(),
+            "nvmlDeviceGetNvLinkVersion",
+        )?;
 
         unsafe {
             let mut version: c_uint = mem::zeroed();
@@ -150,6 +157,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceGetNvLinkCapability
                 .as_ref(),
+            "nvmlDeviceGetNvLinkCapability",
         )?;
 
         unsafe {
@@ -196,6 +204,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceGetNvLinkRemotePciInfo_v2
                 .as_ref(),
+            "nvmlDeviceGetNvLinkRemotePciInfo_v2",
         )?;
 
         unsafe {
@@ -231,6 +240,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceGetNvLinkErrorCounter
                 .as_ref(),
+            "nvmlDeviceGetNvLinkErrorCounter",
         )?;
 
         unsafe {
@@ -271,6 +281,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceResetNvLinkErrorCounters
                 .as_ref(),
+            "nvmlDeviceResetNvLinkErrorCounters",
         )?;
 
         unsafe { nvml_try(sym(self.device.handle(), self.link)) }
@@ -310,6 +321,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceSetNvLinkUtilizationControl
                 .as_ref(),
+            "nvmlDeviceSetNvLinkUtilizationControl",
         )?;
 
         unsafe {
@@ -348,6 +360,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceGetNvLinkUtilizationControl
                 .as_ref(),
+            "nvmlDeviceGetNvLinkUtilizationControl",
         )?;
 
         unsafe {
@@ -405,6 +418,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceGetNvLinkUtilizationCounter
                 .as_ref(),
+            "nvmlDeviceGetNvLinkUtilizationCounter",
         )?;
 
         unsafe {
@@ -482,6 +496,7 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceFreezeNvLinkUtilizationCounter
                 .as_ref(),
+            "nvmlDeviceFreezeNvLinkUtilizationCounter",
         )?;
 
         unsafe {
@@ -521,11 +536,63 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceResetNvLinkUtilizationCounter
                 .as_ref(),
+            "nvmlDeviceResetNvLinkUtilizationCounter",
         )?;
 
         unsafe { nvml_try(sym(self.device.handle(), self.link, counter as c_uint)) }
     }
 
+    /**
+    Gets the number of bytes received and sent over this link since its byte
+    counters were last reset.
+
+    This takes care of utilization counter controls for you: `Counter::Zero`
+    is configured to count bytes of every packet type (and reset) the first
+    time it's needed, and read as-is afterwards. Don't also use
+    `.set_utilization_control()` on `Counter::Zero` for this link.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if the `link` or `Device` within this `NvLink` struct instance
+      is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Pascal or newer fully supported devices.
+    */
+    // No-run test written
+    pub fn byte_counters(&mut self) -> Result<UtilizationCounter, NvmlError> {
+        if self.utilization_control(Counter::Zero)? != byte_counting_control() {
+            self.reset_byte_counters()?;
+        }
+
+        self.utilization_counter(Counter::Zero)
+    }
+
+    /**
+    Resets the byte counters read by `.byte_counters()` to zero.
+
+    # Errors
+
+    * `Uninitialized`, if the library has not been successfully initialized
+    * `InvalidArg`, if the `link` or `Device` within this `NvLink` struct instance
+      is invalid
+    * `NotSupported`, if this `Device` doesn't support this feature
+    * `Unknown`, on any unexpected error
+
+    # Device Support
+
+    Supports Pascal or newer fully supported devices.
+    */
+    // No-run test written
+    pub fn reset_byte_counters(&mut self) -> Result<(), NvmlError> {
+        self.set_utilization_control(Counter::Zero, byte_counting_control(), true)
+    }
+
     /**
      Get the bandwidth mode of a NvLink connection
 
@@ -542,7 +609,10 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
     */
     #[doc(alias = "nvmlSystemGetNvLinkBwMode")]
     pub fn bw_mode(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlSystemGetNvlinkBwMode.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlSystemGetNvlinkBwMode.as_ref(),
+            "nvmlSystemGetNvlinkBwMode",
+        )?;
 
         unsafe {
             let mut mode: c_uint = 0;
@@ -567,19 +637,24 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
     */
     #[doc(alias = "nvmlSystemSetNvLinkBwMode")]
     pub fn set_bw_mode(&self, mode: u32) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlSystemSetNvlinkBwMode.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlSystemSetNvlinkBwMode.as_ref(),
+            "nvmlSystemSetNvlinkBwMode",
+        )?;
 
         unsafe { nvml_try(sym(mode)) }
     }
 
     /**
-     Get the NvLink device type for a given link index
+    Get the NvLink device type for a given link index
 
     # Errors
+
     * `Uninitialized`, if the library has not been successfully initialized
     * `InvalidArg`, if the `link` or `Device` within this `NvLink` struct instance
       is invalid
     * `NotSupported`, if this `Device` doesn't support this feature
+    * `UnexpectedVariant`, for which you can read the docs for
     * `Unknown`, on any unexpected error
     */
     #[doc(alias = "nvmlDeviceGetNvLinkRemoteDeviceType")]
@@ -590,16 +665,26 @@ impl<'device, 'nvml: 'device> NvLink<'device, 'nvml> {
                 .lib
                 .nvmlDeviceGetNvLinkRemoteDeviceType
                 .as_ref(),
+            "nvmlDeviceGetNvLinkRemoteDeviceType",
         )?;
 
         unsafe {
-            let device_type: IntDeviceType = IntDeviceType::Unknown;
-            nvml_try(sym(self.device.handle(), link, &mut device_type.as_c()))?;
-            Ok(device_type)
+            let mut device_type: nvmlIntNvLinkDeviceType_t = mem::zeroed();
+            nvml_try(sym(self.device.handle(), link, &mut device_type))?;
+
+            IntDeviceType::try_from(device_type)
         }
     }
 }
 
+// Controls for a utilization counter that counts every byte sent or received.
+fn byte_counting_control() -> UtilizationControl {
+    UtilizationControl {
+        units: UtilizationCountUnit::Bytes,
+        packet_filter: PacketTypes::all(),
+    }
+}
+
 #[cfg(test)]
 #[deny(unused_mut)]
 mod test {
@@ -616,6 +701,13 @@ mod test {
         test_with_link(3, &nvml, |link| link.is_active())
     }
 
+    #[test]
+    #[ignore = "my machine does not support this call"]
+    fn remote_device_type() {
+        let nvml = nvml();
+        test_with_link(3, &nvml, |link| link.remote_device_type(0))
+    }
+
     #[test]
     #[ignore = "my machine does not support this call"]
     fn version() {
@@ -728,6 +820,26 @@ mod test {
         link.reset_utilization_counter(Counter::One).unwrap();
     }
 
+    // This modifies link state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn byte_counters() {
+        let nvml = nvml();
+        let device = device(&nvml);
+        let mut link = device.link_wrapper_for(0);
+
+        link.byte_counters().unwrap();
+    }
+
+    // This modifies link state, so we don't want to actually run the test
+    #[allow(dead_code)]
+    fn reset_byte_counters() {
+        let nvml = nvml();
+        let device = device(&nvml);
+        let mut link = device.link_wrapper_for(0);
+
+        link.reset_byte_counters().unwrap();
+    }
+
     // This modifies link state, so we don't want to actually run the test
     #[allow(dead_code)]
     fn bw_mode() {
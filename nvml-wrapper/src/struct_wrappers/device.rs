@@ -1,8 +1,12 @@
-use crate::bitmasks::device::FbcFlags;
+use crate::bitmasks::device::{FbcFlags, VgpuCompatibilityLimit, VgpuVmCompatibility};
 use crate::enum_wrappers::device::{
-    BridgeChip, Clock, EncoderType, FbcSessionType, PerformanceState, SampleValueType,
+    BridgeChip, Clock, EncoderType, FbcSessionType, GridLicenseFeatureCode, PerformanceState,
+    PstateDomain, SampleValueType,
+};
+use crate::enums::device::{
+    BusIdFormat, FirmwareVersion, GpuFabricState, LicenseExpiryStatus, LicenseState, SampleValue,
+    ThermalController, ThermalTarget, UsedGpuMemory,
 };
-use crate::enums::device::{FirmwareVersion, SampleValue, UsedGpuMemory};
 use crate::error::{nvml_try, Bits, NvmlError};
 use crate::ffi::bindings::*;
 use crate::structs::device::FieldId;
@@ -14,7 +18,9 @@ use std::{
 };
 use std::{
     convert::{TryFrom, TryInto},
+    mem,
     os::raw::c_char,
+    ptr, slice, str,
 };
 
 /// PCI information about a GPU device.
@@ -74,6 +80,33 @@ impl PciInfo {
             })
         }
     }
+
+    /**
+    Renders the bus ID of this `PciInfo` in the given format, e.g.
+    `0000:01:00.0` for `BusIdFormat::Legacy`.
+
+    The domain, bus and device come from the corresponding fields; the function
+    number is taken from `bus_id` (and is 0 if it can't be parsed from there).
+    */
+    pub fn formatted_bus_id(&self, format: BusIdFormat) -> String {
+        let function = self
+            .bus_id
+            .rsplit('.')
+            .next()
+            .and_then(|f| u32::from_str_radix(f, 16).ok())
+            .unwrap_or(0);
+
+        match format {
+            BusIdFormat::Legacy => format!(
+                "{:04X}:{:02X}:{:02X}.{:X}",
+                self.domain, self.bus, self.device, function
+            ),
+            BusIdFormat::Extended => format!(
+                "{:08X}:{:02X}:{:02X}.{:X}",
+                self.domain, self.bus, self.device, function
+            ),
+        }
+    }
 }
 
 impl TryInto<nvmlPciInfo_t> for PciInfo {
@@ -133,6 +166,59 @@ impl TryInto<nvmlPciInfo_t> for PciInfo {
     }
 }
 
+/// Extended PCI information about a GPU device.
+///
+/// Returned from `Device.pci_info_ext()`.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PciInfoExt {
+    /// The bus on which the device resides, 0 to 0xff.
+    pub bus: u32,
+    /// The PCI identifier.
+    pub bus_id: String,
+    /// The device's ID on the bus, 0 to 31.
+    pub device: u32,
+    /// The PCI domain on which the device's bus resides, 0 to 0xffffffff.
+    pub domain: u32,
+    /// The combined 16-bit device ID and 16-bit vendor ID.
+    pub pci_device_id: u32,
+    /// The 32-bit Sub System Device ID.
+    pub pci_sub_system_id: u32,
+    /// The 8-bit PCI base class code.
+    pub base_class: u32,
+    /// The 8-bit PCI sub class code.
+    pub sub_class: u32,
+}
+
+impl TryFrom<nvmlPciInfoExt_t> for PciInfoExt {
+    type Error = NvmlError;
+
+    /**
+    Construct `PciInfoExt` from the corresponding C struct.
+
+    # Errors
+
+    * `Utf8Error`, if the string obtained from the C function is not valid Utf8
+    */
+    fn try_from(value: nvmlPciInfoExt_t) -> Result<Self, Self::Error> {
+        unsafe {
+            let bus_id_raw = CStr::from_ptr(value.busId.as_ptr());
+
+            Ok(Self {
+                bus: value.bus,
+                bus_id: bus_id_raw.to_str()?.into(),
+                device: value.device,
+                domain: value.domain,
+                pci_device_id: value.pciDeviceId,
+                pci_sub_system_id: value.pciSubSystemId,
+                base_class: value.baseClass,
+                sub_class: value.subClass,
+            })
+        }
+    }
+}
+
 /// BAR1 memory allocation information for a device (in bytes)
 // Checked against local
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -363,6 +449,33 @@ impl From<nvmlViolationTime_t> for ViolationTime {
     }
 }
 
+/// A thermal sensor on a `Device` and its current reading.
+///
+/// Returned from `Device.thermal_settings()`. Temperatures are in °C.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ThermalSensor {
+    pub controller: ThermalController,
+    pub default_min_temp: i32,
+    pub default_max_temp: i32,
+    pub current_temp: i32,
+    /// The component this sensor measures.
+    pub target: ThermalTarget,
+}
+
+impl From<nvmlGpuThermalSettings_t__bindgen_ty_1> for ThermalSensor {
+    fn from(struct_: nvmlGpuThermalSettings_t__bindgen_ty_1) -> Self {
+        Self {
+            controller: struct_.controller.into(),
+            default_min_temp: struct_.defaultMinTemp,
+            default_max_temp: struct_.defaultMaxTemp,
+            current_temp: struct_.currentTemp,
+            target: struct_.target.into(),
+        }
+    }
+}
+
 /**
 Accounting statistics for a process.
 
@@ -707,6 +820,80 @@ impl From<nvmlDeviceAttributes_t> for DeviceAttributes {
     }
 }
 
+/// Utilization information for a single domain within `DynamicPstatesInfo`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DomainUtilization {
+    /// Whether this utilization domain is present on the device.
+    pub is_present: bool,
+    /// Percentage of time where the domain was considered busy in the last
+    /// 1-second interval.
+    pub percentage: u32,
+    /// Utilization threshold that can trigger a perf-increasing P-State change
+    /// when crossed.
+    pub inc_threshold: u32,
+    /// Utilization threshold that can trigger a perf-decreasing P-State change
+    /// when crossed.
+    pub dec_threshold: u32,
+}
+
+impl From<nvmlGpuDynamicPstatesInfo_st__bindgen_ty_1> for DomainUtilization {
+    fn from(struct_: nvmlGpuDynamicPstatesInfo_st__bindgen_ty_1) -> Self {
+        Self {
+            is_present: struct_.bIsPresent > 0,
+            percentage: struct_.percentage,
+            inc_threshold: struct_.incThreshold,
+            dec_threshold: struct_.decThreshold,
+        }
+    }
+}
+
+/// Per-domain utilization used by a device to drive P-State changes.
+///
+/// Returned from `Device.dynamic_pstates_info()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DynamicPstatesInfo {
+    /// Reserved for future use.
+    pub flags: u32,
+    /// Utilization for each domain, indexed by `PstateDomain`.
+    ///
+    /// Use `.domain()` to look up a specific domain.
+    pub utilization: [DomainUtilization; NVML_MAX_GPU_UTILIZATIONS as usize],
+}
+
+impl DynamicPstatesInfo {
+    /// Get the utilization information for the given domain.
+    pub fn domain(&self, domain: PstateDomain) -> &DomainUtilization {
+        &self.utilization[domain.as_c() as usize]
+    }
+}
+
+impl From<nvmlGpuDynamicPstatesInfo_t> for DynamicPstatesInfo {
+    fn from(struct_: nvmlGpuDynamicPstatesInfo_t) -> Self {
+        Self {
+            flags: struct_.flags,
+            utilization: struct_.utilization.map(DomainUtilization::from),
+        }
+    }
+}
+
+/// C2C (chip-to-chip) mode information for a device.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct C2cModeInfo {
+    /// Whether the device's C2C link is enabled.
+    pub is_c2c_enabled: bool,
+}
+
+impl From<nvmlC2cModeInfo_v1_t> for C2cModeInfo {
+    fn from(struct_: nvmlC2cModeInfo_v1_t) -> Self {
+        Self {
+            is_c2c_enabled: struct_.isC2cEnabled > 0,
+        }
+    }
+}
+
 /// Fan speed info
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -811,6 +998,115 @@ impl From<nvmlGpuInstancePlacement_t> for GpuInstancePlacement {
     }
 }
 
+/// MIG compute instance profile info.
+///
+/// Returned from `GpuInstance.compute_instance_profile_info()`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComputeInstanceProfileInfo {
+    /// Unique profile ID within the GPU instance
+    pub id: u32,
+    /// GPU slice count
+    pub slice_count: u32,
+    /// Compute instance count
+    pub instance_count: u32,
+    /// Streaming multiprocessor count
+    pub multiprocessor_count: u32,
+    /// Shared copy engine count
+    pub shared_copy_engine_count: u32,
+    /// Shared decoder engine count
+    pub shared_decoder_count: u32,
+    /// Shared encoder engine count
+    pub shared_encoder_count: u32,
+    /// Shared JPEG engine count
+    pub shared_jpeg_count: u32,
+    /// Shared OFA engine count
+    pub shared_ofa_count: u32,
+}
+
+impl From<nvmlComputeInstanceProfileInfo_t> for ComputeInstanceProfileInfo {
+    fn from(struct_: nvmlComputeInstanceProfileInfo_t) -> Self {
+        Self {
+            id: struct_.id,
+            slice_count: struct_.sliceCount,
+            instance_count: struct_.instanceCount,
+            multiprocessor_count: struct_.multiprocessorCount,
+            shared_copy_engine_count: struct_.sharedCopyEngineCount,
+            shared_decoder_count: struct_.sharedDecoderCount,
+            shared_encoder_count: struct_.sharedEncoderCount,
+            shared_jpeg_count: struct_.sharedJpegCount,
+            shared_ofa_count: struct_.sharedOfaCount,
+        }
+    }
+}
+
+/// Information about a MIG GPU instance.
+///
+/// Returned from `GpuInstance.info()`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpuInstanceInfo {
+    /// Unique ID of the GPU instance on its `Device`
+    pub id: u32,
+    /// GPU instance profile ID
+    pub profile_id: u32,
+    /// Placement of the GPU instance on its `Device`
+    pub placement: GpuInstancePlacement,
+}
+
+impl From<nvmlGpuInstanceInfo_t> for GpuInstanceInfo {
+    fn from(struct_: nvmlGpuInstanceInfo_t) -> Self {
+        Self {
+            id: struct_.id,
+            profile_id: struct_.profileId,
+            placement: struct_.placement.into(),
+        }
+    }
+}
+
+/// MIG compute instance placements
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComputeInstancePlacement {
+    /// GPU slices occupied
+    pub size: u32,
+    /// Index of first occupied GPU slice (inclusive)
+    pub start: u32,
+}
+
+impl From<nvmlComputeInstancePlacement_t> for ComputeInstancePlacement {
+    fn from(value: nvmlComputeInstancePlacement_t) -> Self {
+        Self {
+            size: value.size,
+            start: value.start,
+        }
+    }
+}
+
+/// Information about a MIG compute instance.
+///
+/// Returned from `ComputeInstance.info()`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComputeInstanceInfo {
+    /// Unique ID of the compute instance within its GPU instance
+    pub id: u32,
+    /// Compute instance profile ID
+    pub profile_id: u32,
+    /// Placement of the compute instance within its GPU instance
+    pub placement: ComputeInstancePlacement,
+}
+
+impl From<nvmlComputeInstanceInfo_t> for ComputeInstanceInfo {
+    fn from(struct_: nvmlComputeInstanceInfo_t) -> Self {
+        Self {
+            id: struct_.id,
+            profile_id: struct_.profileId,
+            placement: struct_.placement.into(),
+        }
+    }
+}
+
 // Vgpu
 /// Vgpu scheduler capabilities
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -878,6 +1174,484 @@ impl VgpuVersion {
     }
 }
 
+/// Per-engine utilization of a single vGPU instance.
+///
+/// Returned from `Device.vgpu_instances_utilization()`.
+// Checked against local
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VgpuInstanceUtilizationInfo {
+    /// CPU timestamp in μs
+    pub timestamp: u64,
+    /// The vGPU instance this sample is for
+    pub vgpu_instance: u32,
+    /// SM (3D/Compute) utilization
+    pub sm: SampleValue,
+    /// Frame buffer utilization
+    pub memory: SampleValue,
+    /// Encoder utilization
+    pub encoder: SampleValue,
+    /// Decoder utilization
+    pub decoder: SampleValue,
+    /// JPEG engine utilization
+    pub jpeg: SampleValue,
+    /// Optical flow accelerator utilization
+    pub ofa: SampleValue,
+}
+
+impl VgpuInstanceUtilizationInfo {
+    /// Given the value type NVML reported and the C struct, returns a Rust
+    /// struct with the correct union variants.
+    pub fn from_tag_and_struct(
+        tag: &SampleValueType,
+        struct_: nvmlVgpuInstanceUtilizationInfo_v1_t,
+    ) -> Self {
+        Self {
+            timestamp: struct_.timeStamp,
+            vgpu_instance: struct_.vgpuInstance,
+            sm: SampleValue::from_tag_and_union(tag, struct_.smUtil),
+            memory: SampleValue::from_tag_and_union(tag, struct_.memUtil),
+            encoder: SampleValue::from_tag_and_union(tag, struct_.encUtil),
+            decoder: SampleValue::from_tag_and_union(tag, struct_.decUtil),
+            jpeg: SampleValue::from_tag_and_union(tag, struct_.jpgUtil),
+            ofa: SampleValue::from_tag_and_union(tag, struct_.ofaUtil),
+        }
+    }
+}
+
+/// The placements available to a vGPU type on a `Device`.
+///
+/// Returned from `VgpuType.supported_placements()` and
+/// `VgpuType.creatable_placements()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VgpuPlacementList {
+    /// The number of slots occupied by a vGPU of this type.
+    pub placement_size: u32,
+    /// The IDs of the placements (starting slot offsets) a vGPU of this type
+    /// can occupy.
+    pub placement_ids: Vec<u32>,
+}
+
+/// vGPU metadata for a running vGPU instance and its associated VM.
+///
+/// Returned from `Device.vgpu_instance_metadata()`.
+///
+/// A VMM may persist `raw` alongside the VM and later rebuild this struct with
+/// [`VgpuMetadata::try_from_raw()`] to pass it to `Nvml.vgpu_compatibility()`.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VgpuMetadata {
+    /// The version of the metadata structure.
+    pub version: u32,
+    /// The revision of the metadata structure.
+    pub revision: u32,
+    /// Whether the guest-dependent fields (such as `guest_driver_version`)
+    /// have been populated.
+    pub guest_info_initialized: bool,
+    /// The version of the NVIDIA driver installed in the guest.
+    pub guest_driver_version: String,
+    /// The version of the NVIDIA driver installed on the host.
+    pub host_driver_version: String,
+    /// Whether the vGPU supports migration.
+    pub migration_supported: bool,
+    /// The vGPU version of the guest driver.
+    pub guest_vgpu_version: u32,
+    /// The raw metadata buffer as returned by NVML, including the opaque data
+    /// section.
+    pub raw: Vec<u8>,
+}
+
+impl VgpuMetadata {
+    /**
+    Parses a raw metadata buffer as returned by NVML.
+
+    # Errors
+
+    * `InsufficientSize`, if `raw` is smaller than the fixed-size portion of
+      the C struct
+    * `MissingNulTerminator`, if a driver version string is not nul-terminated
+    * `Utf8Error`, if a driver version string could not be interpreted as UTF-8
+    */
+    pub fn try_from_raw(raw: Vec<u8>) -> Result<Self, NvmlError> {
+        let metadata: nvmlVgpuMetadata_t = read_metadata_header(&raw)?;
+
+        Ok(Self {
+            version: metadata.version,
+            revision: metadata.revision,
+            guest_info_initialized: metadata.guestInfoState
+                == nvmlVgpuGuestInfoState_enum_NVML_VGPU_INSTANCE_GUEST_INFO_STATE_INITIALIZED,
+            guest_driver_version: str_from_c_array(&metadata.guestDriverVersion)?.into(),
+            host_driver_version: str_from_c_array(&metadata.hostDriverVersion)?.into(),
+            migration_supported: metadata.vgpuVirtualizationCaps
+                & NVML_VGPU_VIRTUALIZATION_CAP_MIGRATION_YES
+                != 0,
+            guest_vgpu_version: metadata.guestVgpuVersion,
+            raw,
+        })
+    }
+
+    /// Copies `raw` into a buffer suitably aligned to be passed to NVML as an
+    /// `nvmlVgpuMetadata_t`.
+    pub(crate) fn as_c_buffer(&self) -> Vec<u32> {
+        aligned_metadata_buffer::<nvmlVgpuMetadata_t>(&self.raw)
+    }
+}
+
+/// vGPU metadata for a physical GPU.
+///
+/// Returned from `Device.vgpu_metadata()`.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VgpuPgpuMetadata {
+    /// The version of the metadata structure.
+    pub version: u32,
+    /// The revision of the metadata structure.
+    pub revision: u32,
+    /// The version of the NVIDIA driver installed on the host.
+    pub host_driver_version: String,
+    /// Whether the physical GPU supports vGPU migration.
+    pub migration_supported: bool,
+    /// The range of vGPU versions supported by the host driver.
+    pub host_supported_vgpu_range: VgpuVersion,
+    /// The raw metadata buffer as returned by NVML, including the opaque data
+    /// section.
+    pub raw: Vec<u8>,
+}
+
+impl VgpuPgpuMetadata {
+    /**
+    Parses a raw metadata buffer as returned by NVML.
+
+    # Errors
+
+    * `InsufficientSize`, if `raw` is smaller than the fixed-size portion of
+      the C struct
+    * `MissingNulTerminator`, if the driver version string is not nul-terminated
+    * `Utf8Error`, if the driver version string could not be interpreted as UTF-8
+    */
+    pub fn try_from_raw(raw: Vec<u8>) -> Result<Self, NvmlError> {
+        let metadata: nvmlVgpuPgpuMetadata_t = read_metadata_header(&raw)?;
+
+        Ok(Self {
+            version: metadata.version,
+            revision: metadata.revision,
+            host_driver_version: str_from_c_array(&metadata.hostDriverVersion)?.into(),
+            migration_supported: metadata.pgpuVirtualizationCaps
+                & NVML_VGPU_PGPU_VIRTUALIZATION_CAP_MIGRATION_YES
+                != 0,
+            host_supported_vgpu_range: VgpuVersion::from(metadata.hostSupportedVgpuRange),
+            raw,
+        })
+    }
+
+    /// Copies `raw` into a buffer suitably aligned to be passed to NVML as an
+    /// `nvmlVgpuPgpuMetadata_t`.
+    pub(crate) fn as_c_buffer(&self) -> Vec<u32> {
+        aligned_metadata_buffer::<nvmlVgpuPgpuMetadata_t>(&self.raw)
+    }
+}
+
+// The metadata structs end in a 4-byte placeholder for the variable-length
+// opaque data section.
+fn read_metadata_header<T: Copy>(raw: &[u8]) -> Result<T, NvmlError> {
+    let header_size = mem::size_of::<T>() - 4;
+
+    if raw.len() < header_size {
+        return Err(NvmlError::InsufficientSize(Some(header_size)));
+    }
+
+    unsafe {
+        let mut header: T = mem::zeroed();
+        ptr::copy_nonoverlapping(
+            raw.as_ptr(),
+            &mut header as *mut T as *mut u8,
+            raw.len().min(mem::size_of::<T>()),
+        );
+
+        Ok(header)
+    }
+}
+
+// Reads the string stored in a fixed-size C array without reading past its
+// end, as the array may come from a caller-supplied buffer.
+fn str_from_c_array(array: &[c_char]) -> Result<&str, NvmlError> {
+    // Safe because `c_char` and `u8` have the same size and alignment
+    let bytes = unsafe { slice::from_raw_parts(array.as_ptr() as *const u8, array.len()) };
+    let len = bytes
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or(NvmlError::MissingNulTerminator)?;
+
+    Ok(str::from_utf8(&bytes[..len])?)
+}
+
+fn aligned_metadata_buffer<T>(raw: &[u8]) -> Vec<u32> {
+    let size = raw.len().max(mem::size_of::<T>());
+    let mut buffer: Vec<u32> = vec![0; (size + 3) / 4];
+
+    unsafe {
+        ptr::copy_nonoverlapping(raw.as_ptr(), buffer.as_mut_ptr() as *mut u8, raw.len());
+    }
+
+    buffer
+}
+
+/// Compatibility of a vGPU instance with a physical GPU.
+///
+/// Returned from `Nvml.vgpu_compatibility()`.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VgpuPgpuCompatibility {
+    /// The states in which the vGPU / VM may be booted on the physical GPU.
+    pub vm_compatibility: VgpuVmCompatibility,
+    /// The factors limiting compatibility, if any.
+    pub compatibility_limit: VgpuCompatibilityLimit,
+}
+
+impl TryFrom<nvmlVgpuPgpuCompatibility_t> for VgpuPgpuCompatibility {
+    type Error = NvmlError;
+
+    /**
+    Construct `VgpuPgpuCompatibility` from the corresponding C struct.
+
+    # Errors
+
+    * `IncorrectBits`, if either bitmask contains bits that do not correspond
+      to a known flag
+    */
+    fn try_from(value: nvmlVgpuPgpuCompatibility_t) -> Result<Self, Self::Error> {
+        Ok(Self {
+            vm_compatibility: VgpuVmCompatibility::from_bits(value.vgpuVmCompatibility).ok_or(
+                NvmlError::IncorrectBits(Bits::U32(value.vgpuVmCompatibility)),
+            )?,
+            compatibility_limit: VgpuCompatibilityLimit::from_bits(value.compatibilityLimitCode)
+                .ok_or(NvmlError::IncorrectBits(Bits::U32(
+                    value.compatibilityLimitCode,
+                )))?,
+        })
+    }
+}
+
+/// The expiry of a GRID or vGPU license.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LicenseExpiry {
+    pub year: u32,
+    pub month: u16,
+    pub day: u16,
+    pub hour: u16,
+    pub min: u16,
+    pub sec: u16,
+    /// The status of the expiry. The other fields are only meaningful if
+    /// this is `LicenseExpiryStatus::Valid`.
+    pub status: LicenseExpiryStatus,
+}
+
+impl TryFrom<nvmlGridLicenseExpiry_t> for LicenseExpiry {
+    type Error = NvmlError;
+
+    /**
+    Construct `LicenseExpiry` from the corresponding C struct.
+
+    # Errors
+
+    * `UnexpectedVariant`, for which you can read the docs for
+    */
+    fn try_from(value: nvmlGridLicenseExpiry_t) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: value.year,
+            month: value.month,
+            day: value.day,
+            hour: value.hour,
+            min: value.min,
+            sec: value.sec,
+            status: LicenseExpiryStatus::try_from(value.status as u32)?,
+        })
+    }
+}
+
+impl TryFrom<nvmlVgpuLicenseExpiry_t> for LicenseExpiry {
+    type Error = NvmlError;
+
+    /**
+    Construct `LicenseExpiry` from the corresponding C struct.
+
+    # Errors
+
+    * `UnexpectedVariant`, for which you can read the docs for
+    */
+    fn try_from(value: nvmlVgpuLicenseExpiry_t) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: value.year,
+            month: value.month,
+            day: value.day,
+            hour: value.hour,
+            min: value.min,
+            sec: value.sec,
+            status: LicenseExpiryStatus::try_from(value.status as u32)?,
+        })
+    }
+}
+
+/// A GRID licensable feature.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridLicensableFeature {
+    pub feature_code: GridLicenseFeatureCode,
+    /// Whether this feature is currently licensed.
+    pub is_licensed: bool,
+    /// The licensed product name and version.
+    pub license_info: String,
+    /// The product name of this feature.
+    pub product_name: String,
+    /// Whether this feature is enabled.
+    pub feature_enabled: bool,
+    pub license_expiry: LicenseExpiry,
+}
+
+impl TryFrom<nvmlGridLicensableFeature_t> for GridLicensableFeature {
+    type Error = NvmlError;
+
+    /**
+    Construct `GridLicensableFeature` from the corresponding C struct.
+
+    # Errors
+
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Utf8Error`, if a string could not be interpreted as UTF-8
+    */
+    fn try_from(value: nvmlGridLicensableFeature_t) -> Result<Self, Self::Error> {
+        unsafe {
+            let license_info = CStr::from_ptr(value.licenseInfo.as_ptr());
+            let product_name = CStr::from_ptr(value.productName.as_ptr());
+
+            Ok(Self {
+                feature_code: GridLicenseFeatureCode::try_from(value.featureCode)?,
+                is_licensed: value.featureState != 0,
+                license_info: license_info.to_str()?.into(),
+                product_name: product_name.to_str()?.into(),
+                feature_enabled: value.featureEnabled != 0,
+                license_expiry: LicenseExpiry::try_from(value.licenseExpiry)?,
+            })
+        }
+    }
+}
+
+/// GRID licensable features of a `Device`.
+///
+/// Returned from `Device.grid_licensable_features()`.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridLicensableFeatures {
+    /// Whether GRID licensing is supported on the `Device`.
+    pub is_grid_license_supported: bool,
+    pub features: Vec<GridLicensableFeature>,
+}
+
+impl TryFrom<nvmlGridLicensableFeatures_t> for GridLicensableFeatures {
+    type Error = NvmlError;
+
+    /**
+    Construct `GridLicensableFeatures` from the corresponding C struct.
+
+    # Errors
+
+    * `UnexpectedVariant`, for which you can read the docs for
+    * `Utf8Error`, if a string could not be interpreted as UTF-8
+    */
+    fn try_from(value: nvmlGridLicensableFeatures_t) -> Result<Self, Self::Error> {
+        let count =
+            (value.licensableFeaturesCount as usize).min(value.gridLicensableFeatures.len());
+
+        Ok(Self {
+            is_grid_license_supported: value.isGridLicenseSupported != 0,
+            features: value.gridLicensableFeatures[..count]
+                .iter()
+                .map(|f| GridLicensableFeature::try_from(*f))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// License information for a vGPU instance.
+///
+/// Returned from `Device.vgpu_license_info()`.
+// Checked against local
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VgpuLicenseInfo {
+    /// Whether the vGPU instance is licensed.
+    pub is_licensed: bool,
+    pub license_expiry: LicenseExpiry,
+    pub current_state: LicenseState,
+}
+
+impl TryFrom<nvmlVgpuLicenseInfo_t> for VgpuLicenseInfo {
+    type Error = NvmlError;
+
+    /**
+    Construct `VgpuLicenseInfo` from the corresponding C struct.
+
+    # Errors
+
+    * `UnexpectedVariant`, for which you can read the docs for
+    */
+    fn try_from(value: nvmlVgpuLicenseInfo_t) -> Result<Self, Self::Error> {
+        Ok(Self {
+            is_licensed: value.isLicensed != 0,
+            license_expiry: LicenseExpiry::try_from(value.licenseExpiry)?,
+            current_state: LicenseState::try_from(value.currentState)?,
+        })
+    }
+}
+
+/// Information about a `Device`'s registration with the NvLink fabric.
+///
+/// Returned from `Device.gpu_fabric_info()`.
+// TODO: Missing a lot of derives because of the `Result`
+#[derive(Debug)]
+pub struct GpuFabricInfo {
+    /// UUID of the cluster this GPU belongs to.
+    pub cluster_uuid: [u8; 16],
+    /// The result of the fabric probe. Only meaningful once `state` is
+    /// `GpuFabricState::Completed`.
+    pub status: Result<(), NvmlError>,
+    /// ID of the fabric clique this GPU belongs to.
+    pub clique_id: u32,
+    pub state: GpuFabricState,
+    /// Fabric health bitmask; decode it with the
+    /// `NVML_GPU_FABRIC_HEALTH_MASK_*` constants.
+    pub health_mask: u32,
+}
+
+impl TryFrom<nvmlGpuFabricInfo_v2_t> for GpuFabricInfo {
+    type Error = NvmlError;
+
+    /**
+    Construct `GpuFabricInfo` from the corresponding C struct.
+
+    # Errors
+
+    * `UnexpectedVariant`, for which you can read the docs for
+    */
+    fn try_from(value: nvmlGpuFabricInfo_v2_t) -> Result<Self, Self::Error> {
+        Ok(Self {
+            cluster_uuid: value.clusterUuid,
+            status: nvml_try(value.status),
+            clique_id: value.cliqueId,
+            state: GpuFabricState::try_from(value.state as u32)?,
+            health_mask: value.healthMask,
+        })
+    }
+}
+
 /// Vgpu scheduler Params
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1052,7 +1826,10 @@ mod tests {
                 .try_into()
                 .expect("converted c pci info");
 
-            let sym = nvml_sym(nvml.lib.nvmlDeviceGetPciInfo_v3.as_ref())?;
+            let sym = nvml_sym(
+                nvml.lib.nvmlDeviceGetPciInfo_v3.as_ref(),
+                "nvmlDeviceGetPciInfo_v3",
+            )?;
 
             let raw = unsafe {
                 let mut pci_info: nvmlPciInfo_t = mem::zeroed();
@@ -1070,4 +1847,43 @@ mod tests {
             Ok(())
         })
     }
+
+    fn metadata_bytes<T>(metadata: &T) -> Vec<u8> {
+        unsafe {
+            std::slice::from_raw_parts(metadata as *const T as *const u8, mem::size_of::<T>())
+                .to_vec()
+        }
+    }
+
+    #[test]
+    fn vgpu_metadata_from_raw() {
+        let mut metadata: nvmlVgpuMetadata_t = unsafe { mem::zeroed() };
+        metadata.guestDriverVersion[..3].copy_from_slice(&[b'5' as _, b'5' as _, b'0' as _]);
+        metadata.hostDriverVersion[..3].copy_from_slice(&[b'5' as _, b'6' as _, b'0' as _]);
+
+        let parsed = super::VgpuMetadata::try_from_raw(metadata_bytes(&metadata)).unwrap();
+
+        assert_eq!(parsed.guest_driver_version, "550");
+        assert_eq!(parsed.host_driver_version, "560");
+    }
+
+    #[test]
+    fn vgpu_metadata_from_raw_without_nul_terminator() {
+        let mut metadata: nvmlVgpuMetadata_t = unsafe { mem::zeroed() };
+        metadata.guestDriverVersion = [b'x' as _; NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE as usize];
+
+        let result = super::VgpuMetadata::try_from_raw(metadata_bytes(&metadata));
+
+        assert!(matches!(result, Err(NvmlError::MissingNulTerminator)));
+    }
+
+    #[test]
+    fn vgpu_pgpu_metadata_from_raw_without_nul_terminator() {
+        let mut metadata: nvmlVgpuPgpuMetadata_t = unsafe { mem::zeroed() };
+        metadata.hostDriverVersion = [b'x' as _; NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE as usize];
+
+        let result = super::VgpuPgpuMetadata::try_from_raw(metadata_bytes(&metadata));
+
+        assert!(matches!(result, Err(NvmlError::MissingNulTerminator)));
+    }
 }
@@ -11,12 +11,20 @@ use crate::ffi::bindings::*;
 use serde_derive::{Deserialize, Serialize};
 use std::{convert::TryFrom, ffi::CStr};
 
-/// Information about an excluded device.
+/**
+Information about an excluded device.
+
+Returned from `Nvml.excluded_device_info()`. NVML only reports the PCI info and
+UUID of excluded devices; they have no GPU or compute instances since they
+cannot be used.
+*/
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExcludedDeviceInfo {
-    pci_info: PciInfo,
-    uuid: String,
+    /// PCI information for the excluded device.
+    pub pci_info: PciInfo,
+    /// The UUID of the excluded device.
+    pub uuid: String,
 }
 
 impl TryFrom<nvmlExcludedDeviceInfo_t> for ExcludedDeviceInfo {
@@ -0,0 +1,264 @@
+use crate::error::NvmlError;
+use crate::ffi::bindings::NVML_CC_GPU_CEC_NONCE_SIZE;
+use crate::structs::device::ConfidentialComputeGpuAttestationReport;
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+const NONCE_SIZE: usize = NVML_CC_GPU_CEC_NONCE_SIZE as usize;
+
+/// Size of the SPDM `GET_MEASUREMENTS` request that prefixes the report:
+/// a 4-byte header, the 32-byte nonce and a 1-byte slot ID.
+const SPDM_REQUEST_SIZE: usize = 4 + NONCE_SIZE + 1;
+
+/// Bit 0 of a measurement block's `MeasurementSpecification` field indicates
+/// that the measurement is in the DMTF format.
+const DMTF_MEASUREMENT_SPECIFICATION: u8 = 1;
+
+/// A typed view over the SPDM `GET_MEASUREMENTS` exchange contained in
+/// [`ConfidentialComputeGpuAttestationReport::attestation_report`].
+///
+/// Returned from `ConfidentialComputeGpuAttestationReport.parse()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpdmAttestationReport {
+    /// The SPDM version of the response (e.g. `0x11` for SPDM 1.1).
+    pub spdm_version: u8,
+    /// The nonce sent in the request, i.e. the nonce that was passed to
+    /// `Device.confidential_compute_gpu_attestation_report()`.
+    pub request_nonce: [u8; NONCE_SIZE],
+    /// The measurement blocks contained in the response.
+    pub measurement_blocks: Vec<SpdmMeasurementBlock>,
+    /// The nonce generated by the GPU for the response.
+    pub response_nonce: [u8; NONCE_SIZE],
+    /// Opaque data appended to the response.
+    pub opaque_data: Vec<u8>,
+    /// The signature over the request and response.
+    pub signature: Vec<u8>,
+}
+
+impl SpdmAttestationReport {
+    /// Returns `true` if the nonce echoed in the report matches the given nonce.
+    pub fn nonce_matches(&self, nonce: &[u8; NONCE_SIZE]) -> bool {
+        &self.request_nonce == nonce
+    }
+}
+
+/// A single SPDM measurement block.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpdmMeasurementBlock {
+    /// The index of this measurement.
+    pub index: u8,
+    /// The `MeasurementSpecification` bitmask of this block.
+    pub measurement_specification: u8,
+    /// The DMTF measurement value type.
+    ///
+    /// `None` if the block is not in the DMTF format.
+    pub value_type: Option<u8>,
+    /// The measurement value (the raw measurement if the block is not in the
+    /// DMTF format).
+    pub value: Vec<u8>,
+}
+
+impl ConfidentialComputeGpuAttestationReport {
+    /**
+    Parses the raw `attestation_report` bytes into a typed view.
+
+    The raw bytes are left untouched; this only splits out the SPDM request
+    and response fields (including the measurement blocks and the echoed
+    nonce) so that callers don't need to re-derive the layout. It does not
+    verify the signature.
+
+    # Errors
+
+    * `MalformedAttestationReport`, if the report is truncated or its lengths
+      are inconsistent
+    */
+    pub fn parse(&self) -> Result<SpdmAttestationReport, NvmlError> {
+        let report = self
+            .attestation_report
+            .get(..self.attestation_report_size as usize)
+            .unwrap_or(&self.attestation_report);
+
+        let mut reader = Reader::new(report);
+
+        // Request: SPDMVersion, RequestResponseCode, Param1, Param2, Nonce, SlotIDParam
+        let request = reader.take(SPDM_REQUEST_SIZE, "request")?;
+        let mut request_nonce = [0; NONCE_SIZE];
+        request_nonce.copy_from_slice(&request[4..4 + NONCE_SIZE]);
+
+        // Response: SPDMVersion, RequestResponseCode, Param1, Param2
+        let header = reader.take(4, "response header")?;
+        let spdm_version = header[0];
+
+        let number_of_blocks = reader.take(1, "number of blocks")?[0];
+        let record_length = reader.take(3, "measurement record length")?;
+        let record_length =
+            u32::from_le_bytes([record_length[0], record_length[1], record_length[2], 0]);
+        let record = reader.take(record_length as usize, "measurement record")?;
+
+        let mut record_reader = Reader::new(record);
+        let mut measurement_blocks = Vec::with_capacity(number_of_blocks as usize);
+
+        for _ in 0..number_of_blocks {
+            measurement_blocks.push(parse_measurement_block(&mut record_reader)?);
+        }
+
+        if !record_reader.is_empty() {
+            return Err(NvmlError::MalformedAttestationReport(
+                "measurement record has trailing bytes",
+            ));
+        }
+
+        let mut response_nonce = [0; NONCE_SIZE];
+        response_nonce.copy_from_slice(reader.take(NONCE_SIZE, "response nonce")?);
+
+        let opaque_length = reader.take_u16("opaque data length")?;
+        let opaque_data = reader.take(opaque_length as usize, "opaque data")?.to_vec();
+
+        Ok(SpdmAttestationReport {
+            spdm_version,
+            request_nonce,
+            measurement_blocks,
+            response_nonce,
+            opaque_data,
+            signature: reader.rest().to_vec(),
+        })
+    }
+}
+
+fn parse_measurement_block(reader: &mut Reader) -> Result<SpdmMeasurementBlock, NvmlError> {
+    let index = reader.take(1, "measurement block index")?[0];
+    let measurement_specification = reader.take(1, "measurement specification")?[0];
+    let size = reader.take_u16("measurement size")?;
+    let measurement = reader.take(size as usize, "measurement")?;
+
+    if measurement_specification & DMTF_MEASUREMENT_SPECIFICATION == 0 {
+        return Ok(SpdmMeasurementBlock {
+            index,
+            measurement_specification,
+            value_type: None,
+            value: measurement.to_vec(),
+        });
+    }
+
+    let mut measurement_reader = Reader::new(measurement);
+    let value_type = measurement_reader.take(1, "measurement value type")?[0];
+    let value_size = measurement_reader.take_u16("measurement value size")?;
+    let value = measurement_reader.take(value_size as usize, "measurement value")?;
+
+    Ok(SpdmMeasurementBlock {
+        index,
+        measurement_specification,
+        value_type: Some(value_type),
+        value: value.to_vec(),
+    })
+}
+
+// Minimal cursor over a byte slice that reports which field was truncated.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn take(&mut self, len: usize, field: &'static str) -> Result<&'a [u8], NvmlError> {
+        if self.bytes.len() < len {
+            return Err(NvmlError::MalformedAttestationReport(field));
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_u16(&mut self, field: &'static str) -> Result<u16, NvmlError> {
+        let bytes = self.take(2, field)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn rest(self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+#[deny(unused_mut)]
+mod test {
+    use super::*;
+
+    fn report(bytes: Vec<u8>) -> ConfidentialComputeGpuAttestationReport {
+        ConfidentialComputeGpuAttestationReport {
+            attestation_report_size: bytes.len() as u32,
+            attestation_report: bytes,
+            is_cec_attestation_report_present: false,
+            cec_attestation_report_size: 0,
+            cec_attestation_report: vec![],
+        }
+    }
+
+    fn sample() -> Vec<u8> {
+        let mut bytes = vec![0x11, 0xe0, 0x01, 0xff];
+        bytes.extend([0xaa; NONCE_SIZE]);
+        bytes.push(0x00);
+
+        // Two blocks: one DMTF with a 4-byte digest, one raw 2-byte measurement
+        let mut record = vec![1, 1, 7, 0, 0x80, 4, 0, 1, 2, 3, 4];
+        record.extend([2, 0, 2, 0, 9, 9]);
+
+        bytes.extend([0x11, 0x60, 0x00, 0x00, 2]);
+        bytes.extend(&(record.len() as u32).to_le_bytes()[..3]);
+        bytes.extend(record);
+        bytes.extend([0xbb; NONCE_SIZE]);
+        bytes.extend([3, 0, 7, 7, 7]);
+        bytes.extend([0xcc; 96]);
+
+        bytes
+    }
+
+    #[test]
+    fn parse_attestation_report() {
+        let parsed = report(sample()).parse().expect("parsed");
+
+        assert_eq!(parsed.spdm_version, 0x11);
+        assert!(parsed.nonce_matches(&[0xaa; NONCE_SIZE]));
+        assert_eq!(parsed.response_nonce, [0xbb; NONCE_SIZE]);
+        assert_eq!(
+            parsed.measurement_blocks,
+            vec![
+                SpdmMeasurementBlock {
+                    index: 1,
+                    measurement_specification: 1,
+                    value_type: Some(0x80),
+                    value: vec![1, 2, 3, 4],
+                },
+                SpdmMeasurementBlock {
+                    index: 2,
+                    measurement_specification: 0,
+                    value_type: None,
+                    value: vec![9, 9],
+                },
+            ]
+        );
+        assert_eq!(parsed.opaque_data, vec![7, 7, 7]);
+        assert_eq!(parsed.signature, vec![0xcc; 96]);
+    }
+
+    #[test]
+    fn parse_truncated_attestation_report() {
+        let mut bytes = sample();
+        bytes.truncate(SPDM_REQUEST_SIZE + 10);
+
+        assert!(matches!(
+            report(bytes).parse(),
+            Err(NvmlError::MalformedAttestationReport("measurement record"))
+        ));
+    }
+}
@@ -1,6 +1,8 @@
+use crate::bitmasks::device::ThrottleReasons;
 #[cfg(target_os = "windows")]
 use crate::enum_wrappers::device::DriverModel;
-use crate::enum_wrappers::device::OperationMode;
+use crate::enum_wrappers::device::{OperationMode, PerformanceState};
+use crate::struct_wrappers::device::{BAR1MemoryInfo, MemoryInfo, Utilization};
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
 
@@ -24,6 +26,9 @@ pub enum ConfidentialComputeCpuCapabilities {
     AmdSev,
     /// Intel TDX confidential compute capabilities.
     IntelTdx,
+    /// A value this wrapper does not recognize (most likely introduced in a
+    /// newer driver).
+    Unknown(u32),
 }
 
 /// The possible GPU capabilities for confidential compute (either not capable or capable)
@@ -34,6 +39,89 @@ pub enum ConfidentialComputeGpuCapabilities {
     Capable,
     /// Not capable.
     NotCapable,
+    /// A value this wrapper does not recognize (most likely introduced in a
+    /// newer driver).
+    Unknown(u32),
+}
+
+/// Returned from `Device.confidential_compute_state()`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConfidentialComputeState {
+    /// The confidential compute environment.
+    pub environment: ConfidentialComputeEnvironment,
+    /// Whether the confidential compute feature is enabled.
+    pub cc_feature: ConfidentialComputeFeature,
+    /// Whether confidential compute devtools mode is on.
+    pub dev_tools_mode: ConfidentialComputeDevToolsMode,
+}
+
+/// The possible confidential compute environments (unavailable, simulation or production)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfidentialComputeEnvironment {
+    /// Confidential compute is unavailable.
+    Unavailable,
+    /// Simulation environment.
+    Sim,
+    /// Production environment.
+    Prod,
+    /// A value this wrapper does not recognize (most likely introduced in a
+    /// newer driver).
+    Unknown(u32),
+}
+
+/// The possible states of the confidential compute feature (either disabled or enabled)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfidentialComputeFeature {
+    /// Disabled.
+    Disabled,
+    /// Enabled.
+    Enabled,
+    /// A value this wrapper does not recognize (most likely introduced in a
+    /// newer driver).
+    Unknown(u32),
+}
+
+/// The possible confidential compute devtools modes (either off or on)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfidentialComputeDevToolsMode {
+    /// Off.
+    Off,
+    /// On.
+    On,
+    /// A value this wrapper does not recognize (most likely introduced in a
+    /// newer driver).
+    Unknown(u32),
+}
+
+/// Returned from `Device.is_confidential_compute_ready()`
+///
+/// Mirrors the output of `nvidia-smi conf-compute -grs`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfidentialComputeReadyState {
+    /// GPUs are accepting client requests.
+    Ready,
+    /// GPUs are not accepting client requests.
+    NotReady,
+    /// A value this wrapper does not recognize (most likely introduced in a
+    /// newer driver).
+    Unknown(u32),
+}
+
+/// Returned from `Device.confidential_compute_key_rotation_threshold()` and
+/// passed to `Device.set_confidential_compute_key_rotation_threshold()`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConfidentialComputeKeyRotationThreshold {
+    /// The maximum attacker advantage before keys are rotated, in the range
+    /// `ffi::bindings::NVML_CC_KEY_ROTATION_THRESHOLD_ATTACKER_ADVANTAGE_MIN`
+    /// to `ffi::bindings::NVML_CC_KEY_ROTATION_THRESHOLD_ATTACKER_ADVANTAGE_MAX`
+    /// (50 to 65). The default is 60.
+    pub max_attacker_advantage: u64,
 }
 
 /// Returned from `Device.confidential_compute_gpu_attestation_report_bytes()`
@@ -70,6 +158,127 @@ pub struct ConfidentialComputeGpuCertificate {
     pub attestation_cert_chain: Vec<u8>,
 }
 
+/// Current clock speeds of a `Device` in MHz.
+///
+/// Returned from `Device.all_clocks()`. Each field is `None` if the `Device`
+/// cannot report that clock.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CurrentClocks {
+    pub graphics: Option<u32>,
+    pub sm: Option<u32>,
+    pub memory: Option<u32>,
+    pub video: Option<u32>,
+}
+
+/// The performance state of a `Device` together with the clocks and throttle
+/// reasons observed in that state.
+///
+/// Returned from `Device.performance_snapshot()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerformanceSnapshot {
+    pub pstate: PerformanceState,
+    /// Graphics clock speed in MHz.
+    pub graphics_clock: u32,
+    /// Memory clock speed in MHz.
+    pub memory_clock: u32,
+    pub throttle_reasons: ThrottleReasons,
+}
+
+/// Framebuffer and BAR1 memory usage of a `Device`, in bytes.
+///
+/// Returned from `Device.memory_report()`. Each region is `None` if the
+/// `Device` cannot report it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemoryReport {
+    /// Framebuffer memory, including the amount reserved by the driver.
+    pub framebuffer: Option<MemoryInfo>,
+    /// BAR1 memory.
+    pub bar1: Option<BAR1MemoryInfo>,
+}
+
+/// Row remapping status of a `Device`'s memory.
+///
+/// Returned from `Device.remapped_rows()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemappedRows {
+    /// Number of rows remapped due to correctable errors.
+    pub corrected_rows: u32,
+    /// Number of rows remapped due to uncorrectable errors.
+    pub uncorrected_rows: u32,
+    /// Whether there are remappings that will take effect after the next
+    /// `Device` reset.
+    pub is_pending: bool,
+    /// Whether a remapping has ever failed.
+    pub failure_occurred: bool,
+}
+
+/// A quick health reading of a `Device`.
+///
+/// Returned from `Device.health_snapshot()`. Each field is `None` if the
+/// `Device` cannot report it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealthSnapshot {
+    /// GPU core temperature in °C.
+    pub temperature: Option<u32>,
+    /// Speed of the first fan as a percentage of its maximum.
+    pub fan_speed_percent: Option<u32>,
+    /// Power usage in milliwatts.
+    pub power_usage_mw: Option<u32>,
+    pub throttle_reasons: Option<ThrottleReasons>,
+    pub utilization: Option<Utilization>,
+}
+
+/// Why a `Device` is currently throttling and how long it has spent throttled.
+///
+/// Returned from `Device.throttling_report()`. Each duration is the cumulative
+/// time in nanoseconds that the `Device` was held below its requested clocks
+/// by that policy, or `None` if the `Device` cannot report it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ThrottlingReport {
+    /// The reasons clocks are being throttled right now.
+    pub active_reasons: ThrottleReasons,
+    pub power_ns: Option<u64>,
+    pub thermal_ns: Option<u64>,
+    pub sync_boost_ns: Option<u64>,
+    pub board_limit_ns: Option<u64>,
+    pub low_utilization_ns: Option<u64>,
+    pub reliability_ns: Option<u64>,
+    /// Time limited by any of the above policies.
+    pub total_app_clocks_ns: Option<u64>,
+    /// Time held below base clocks.
+    pub total_base_clocks_ns: Option<u64>,
+}
+
+/// A power reading taken by the driver.
+///
+/// Returned from `Device.power_samples_since()`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PowerSample {
+    /// CPU timestamp in μs.
+    pub timestamp: u64,
+    /// Power draw in milliwatts.
+    pub milliwatts: u32,
+}
+
+/// PCIe throughput as a percentage of the negotiated link bandwidth.
+///
+/// Returned from `Device.pcie_utilization()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PcieUtilization {
+    /// Percentage of the link's bandwidth used for sending.
+    pub tx_percent: f32,
+    /// Percentage of the link's bandwidth used for receiving.
+    pub rx_percent: f32,
+}
+
 /// Returned from `Device.auto_boosted_clocks_enabled()`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -140,14 +349,47 @@ pub struct EncoderStats {
     pub average_latency: u32,
 }
 
-/// Returned from `Device.cuda_compute_capability()`
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+/**
+Returned from `Device.cuda_compute_capability()`
+
+Capabilities are ordered by major and then minor version, and can be compared
+against `(major, minor)` tuples directly:
+
+```
+# use nvml_wrapper::structs::device::CudaComputeCapability;
+let caps = CudaComputeCapability { major: 8, minor: 6 };
+
+assert!(caps >= (8, 0));
+assert_eq!(caps.sm_arch_string(), "sm_86");
+```
+*/
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CudaComputeCapability {
     pub major: i32,
     pub minor: i32,
 }
 
+impl CudaComputeCapability {
+    /// Formats this capability as a target architecture string as used by
+    /// `nvcc` and PTX, e.g. `sm_80`.
+    pub fn sm_arch_string(&self) -> String {
+        format!("sm_{}{}", self.major, self.minor)
+    }
+}
+
+impl PartialEq<(i32, i32)> for CudaComputeCapability {
+    fn eq(&self, other: &(i32, i32)) -> bool {
+        (self.major, self.minor) == *other
+    }
+}
+
+impl PartialOrd<(i32, i32)> for CudaComputeCapability {
+    fn partial_cmp(&self, other: &(i32, i32)) -> Option<std::cmp::Ordering> {
+        (self.major, self.minor).partial_cmp(other)
+    }
+}
+
 /// Returned from `Device.retired_pages()`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -168,6 +410,333 @@ pub struct RetiredPage {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FieldId(pub u32);
 
+impl FieldId {
+    /**
+    The name of the `sys_exports::field_id` constant that this ID corresponds
+    to (e.g. `"NVML_FI_DEV_POWER_INSTANT"`), for use in logging.
+
+    IDs are interpreted using the numbering of the bundled NVML header. Where
+    several constants share a value, the first one defined is returned.
+    Returns `"unknown"` for IDs that don't match any constant.
+    */
+    pub fn name(&self) -> &'static str {
+        macro_rules! names {
+            ($($id:ident,)*) => {
+                match self.0 {
+                    $(crate::ffi::bindings::field_id::$id => stringify!($id),)*
+                    _ => "unknown",
+                }
+            };
+        }
+
+        names!(
+            NVML_FI_DEV_ECC_CURRENT,
+            NVML_FI_DEV_ECC_PENDING,
+            NVML_FI_DEV_ECC_SBE_VOL_TOTAL,
+            NVML_FI_DEV_ECC_DBE_VOL_TOTAL,
+            NVML_FI_DEV_ECC_SBE_AGG_TOTAL,
+            NVML_FI_DEV_ECC_DBE_AGG_TOTAL,
+            NVML_FI_DEV_ECC_SBE_VOL_L1,
+            NVML_FI_DEV_ECC_DBE_VOL_L1,
+            NVML_FI_DEV_ECC_SBE_VOL_L2,
+            NVML_FI_DEV_ECC_DBE_VOL_L2,
+            NVML_FI_DEV_ECC_SBE_VOL_DEV,
+            NVML_FI_DEV_ECC_DBE_VOL_DEV,
+            NVML_FI_DEV_ECC_SBE_VOL_REG,
+            NVML_FI_DEV_ECC_DBE_VOL_REG,
+            NVML_FI_DEV_ECC_SBE_VOL_TEX,
+            NVML_FI_DEV_ECC_DBE_VOL_TEX,
+            NVML_FI_DEV_ECC_DBE_VOL_CBU,
+            NVML_FI_DEV_ECC_SBE_AGG_L1,
+            NVML_FI_DEV_ECC_DBE_AGG_L1,
+            NVML_FI_DEV_ECC_SBE_AGG_L2,
+            NVML_FI_DEV_ECC_DBE_AGG_L2,
+            NVML_FI_DEV_ECC_SBE_AGG_DEV,
+            NVML_FI_DEV_ECC_DBE_AGG_DEV,
+            NVML_FI_DEV_ECC_SBE_AGG_REG,
+            NVML_FI_DEV_ECC_DBE_AGG_REG,
+            NVML_FI_DEV_ECC_SBE_AGG_TEX,
+            NVML_FI_DEV_ECC_DBE_AGG_TEX,
+            NVML_FI_DEV_ECC_DBE_AGG_CBU,
+            NVML_FI_DEV_RETIRED_SBE,
+            NVML_FI_DEV_RETIRED_DBE,
+            NVML_FI_DEV_RETIRED_PENDING,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L0,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L1,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L2,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L3,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L4,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L5,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_TOTAL,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L0,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L1,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L2,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L3,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L4,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L5,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_TOTAL,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L0,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L1,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L2,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L3,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L4,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L5,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_TOTAL,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L0,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L1,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L2,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L3,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L4,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L5,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_TOTAL,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L0,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L1,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L2,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L3,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L4,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L5,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_TOTAL,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L0,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L1,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L2,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L3,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L4,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L5,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_TOTAL,
+            NVML_FI_DEV_PERF_POLICY_POWER,
+            NVML_FI_DEV_PERF_POLICY_THERMAL,
+            NVML_FI_DEV_PERF_POLICY_SYNC_BOOST,
+            NVML_FI_DEV_PERF_POLICY_BOARD_LIMIT,
+            NVML_FI_DEV_PERF_POLICY_LOW_UTILIZATION,
+            NVML_FI_DEV_PERF_POLICY_RELIABILITY,
+            NVML_FI_DEV_PERF_POLICY_TOTAL_APP_CLOCKS,
+            NVML_FI_DEV_PERF_POLICY_TOTAL_BASE_CLOCKS,
+            NVML_FI_DEV_MEMORY_TEMP,
+            NVML_FI_DEV_TOTAL_ENERGY_CONSUMPTION,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L0,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L1,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L2,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L3,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L4,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L5,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_COMMON,
+            NVML_FI_DEV_NVLINK_LINK_COUNT,
+            NVML_FI_DEV_RETIRED_PENDING_SBE,
+            NVML_FI_DEV_RETIRED_PENDING_DBE,
+            NVML_FI_DEV_PCIE_REPLAY_COUNTER,
+            NVML_FI_DEV_PCIE_REPLAY_ROLLOVER_COUNTER,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L6,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L7,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L8,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L9,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L10,
+            NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L11,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L6,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L7,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L8,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L9,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L10,
+            NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L11,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L6,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L7,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L8,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L9,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L10,
+            NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L11,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L6,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L7,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L8,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L9,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L10,
+            NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L11,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L6,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L7,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L8,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L9,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L10,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L11,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L6,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L7,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L8,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L9,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L10,
+            NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L11,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L6,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L7,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L8,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L9,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L10,
+            NVML_FI_DEV_NVLINK_SPEED_MBPS_L11,
+            NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_TX,
+            NVML_FI_DEV_NVLINK_THROUGHPUT_DATA_RX,
+            NVML_FI_DEV_NVLINK_THROUGHPUT_RAW_TX,
+            NVML_FI_DEV_NVLINK_THROUGHPUT_RAW_RX,
+            NVML_FI_DEV_REMAPPED_COR,
+            NVML_FI_DEV_REMAPPED_UNC,
+            NVML_FI_DEV_REMAPPED_PENDING,
+            NVML_FI_DEV_REMAPPED_FAILURE,
+            NVML_FI_DEV_NVLINK_REMOTE_NVLINK_ID,
+            NVML_FI_DEV_NVSWITCH_CONNECTED_LINK_COUNT,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L0,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L1,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L2,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L3,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L4,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L5,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L6,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L7,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L8,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L9,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L10,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_L11,
+            NVML_FI_DEV_NVLINK_ECC_DATA_ERROR_COUNT_TOTAL,
+            NVML_FI_DEV_NVLINK_ERROR_DL_REPLAY,
+            NVML_FI_DEV_NVLINK_ERROR_DL_RECOVERY,
+            NVML_FI_DEV_NVLINK_ERROR_DL_CRC,
+            NVML_FI_DEV_NVLINK_GET_SPEED,
+            NVML_FI_DEV_NVLINK_GET_STATE,
+            NVML_FI_DEV_NVLINK_GET_VERSION,
+            NVML_FI_DEV_NVLINK_GET_POWER_STATE,
+            NVML_FI_DEV_NVLINK_GET_POWER_THRESHOLD,
+            NVML_FI_DEV_PCIE_L0_TO_RECOVERY_COUNTER,
+            NVML_FI_DEV_C2C_LINK_COUNT,
+            NVML_FI_DEV_C2C_LINK_GET_STATUS,
+            NVML_FI_DEV_C2C_LINK_GET_MAX_BW,
+            NVML_FI_DEV_PCIE_COUNT_CORRECTABLE_ERRORS,
+            NVML_FI_DEV_PCIE_COUNT_NAKS_RECEIVED,
+            NVML_FI_DEV_PCIE_COUNT_RECEIVER_ERROR,
+            NVML_FI_DEV_PCIE_COUNT_BAD_TLP,
+            NVML_FI_DEV_PCIE_COUNT_NAKS_SENT,
+            NVML_FI_DEV_PCIE_COUNT_BAD_DLLP,
+            NVML_FI_DEV_PCIE_COUNT_NON_FATAL_ERROR,
+            NVML_FI_DEV_PCIE_COUNT_FATAL_ERROR,
+            NVML_FI_DEV_PCIE_COUNT_UNSUPPORTED_REQ,
+            NVML_FI_DEV_PCIE_COUNT_LCRC_ERROR,
+            NVML_FI_DEV_PCIE_COUNT_LANE_ERROR,
+            NVML_FI_DEV_IS_RESETLESS_MIG_SUPPORTED,
+            NVML_FI_DEV_POWER_AVERAGE,
+            NVML_FI_DEV_POWER_INSTANT,
+            NVML_FI_DEV_POWER_MIN_LIMIT,
+            NVML_FI_DEV_POWER_MAX_LIMIT,
+            NVML_FI_DEV_POWER_DEFAULT_LIMIT,
+            NVML_FI_DEV_POWER_CURRENT_LIMIT,
+            NVML_FI_DEV_ENERGY,
+            NVML_FI_DEV_POWER_REQUESTED_LIMIT,
+            NVML_FI_DEV_TEMPERATURE_SHUTDOWN_TLIMIT,
+            NVML_FI_DEV_TEMPERATURE_SLOWDOWN_TLIMIT,
+            NVML_FI_DEV_TEMPERATURE_MEM_MAX_TLIMIT,
+            NVML_FI_DEV_TEMPERATURE_GPU_MAX_TLIMIT,
+            NVML_FI_DEV_PCIE_COUNT_TX_BYTES,
+            NVML_FI_DEV_PCIE_COUNT_RX_BYTES,
+            NVML_FI_DEV_IS_MIG_MODE_INDEPENDENT_MIG_QUERY_CAPABLE,
+            NVML_FI_DEV_NVLINK_GET_POWER_THRESHOLD_MAX,
+            NVML_FI_DEV_NVLINK_COUNT_XMIT_PACKETS,
+            NVML_FI_DEV_NVLINK_COUNT_XMIT_BYTES,
+            NVML_FI_DEV_NVLINK_COUNT_RCV_PACKETS,
+            NVML_FI_DEV_NVLINK_COUNT_RCV_BYTES,
+            NVML_FI_DEV_NVLINK_COUNT_VL15_DROPPED,
+            NVML_FI_DEV_NVLINK_COUNT_MALFORMED_PACKET_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_BUFFER_OVERRUN_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_RCV_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_RCV_REMOTE_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_RCV_GENERAL_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_LOCAL_LINK_INTEGRITY_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_XMIT_DISCARDS,
+            NVML_FI_DEV_NVLINK_COUNT_LINK_RECOVERY_SUCCESSFUL_EVENTS,
+            NVML_FI_DEV_NVLINK_COUNT_LINK_RECOVERY_FAILED_EVENTS,
+            NVML_FI_DEV_NVLINK_COUNT_LINK_RECOVERY_EVENTS,
+            NVML_FI_DEV_NVLINK_COUNT_RAW_BER_LANE0,
+            NVML_FI_DEV_NVLINK_COUNT_RAW_BER_LANE1,
+            NVML_FI_DEV_NVLINK_COUNT_RAW_BER,
+            NVML_FI_DEV_NVLINK_COUNT_EFFECTIVE_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_EFFECTIVE_BER,
+            NVML_FI_DEV_NVLINK_COUNT_SYMBOL_ERRORS,
+            NVML_FI_DEV_NVLINK_COUNT_SYMBOL_BER,
+            NVML_FI_DEV_NVLINK_GET_POWER_THRESHOLD_MIN,
+            NVML_FI_DEV_NVLINK_GET_POWER_THRESHOLD_UNITS,
+            NVML_FI_DEV_NVLINK_GET_POWER_THRESHOLD_SUPPORTED,
+            NVML_FI_DEV_RESET_STATUS,
+            NVML_FI_DEV_DRAIN_AND_RESET_STATUS,
+            NVML_FI_DEV_PCIE_OUTBOUND_ATOMICS_MASK,
+            NVML_FI_DEV_PCIE_INBOUND_ATOMICS_MASK,
+            NVML_FI_DEV_GET_GPU_RECOVERY_ACTION,
+            NVML_FI_DEV_C2C_LINK_ERROR_INTR,
+            NVML_FI_DEV_C2C_LINK_ERROR_REPLAY,
+            NVML_FI_DEV_C2C_LINK_ERROR_REPLAY_B2B,
+            NVML_FI_DEV_C2C_LINK_POWER_STATE,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_0,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_1,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_2,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_3,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_4,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_5,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_6,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_7,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_8,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_9,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_10,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_11,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_12,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_13,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_14,
+            NVML_FI_DEV_NVLINK_COUNT_FEC_HISTORY_15,
+            NVML_FI_DEV_CLOCKS_EVENT_REASON_SW_THERM_SLOWDOWN,
+            NVML_FI_DEV_CLOCKS_EVENT_REASON_HW_THERM_SLOWDOWN,
+            NVML_FI_DEV_CLOCKS_EVENT_REASON_HW_POWER_BRAKE_SLOWDOWN,
+            NVML_FI_DEV_POWER_SYNC_BALANCING_FREQ,
+            NVML_FI_DEV_POWER_SYNC_BALANCING_AF,
+            NVML_FI_PWR_SMOOTHING_ENABLED,
+            NVML_FI_PWR_SMOOTHING_PRIV_LVL,
+            NVML_FI_PWR_SMOOTHING_IMM_RAMP_DOWN_ENABLED,
+            NVML_FI_PWR_SMOOTHING_APPLIED_TMP_CEIL,
+            NVML_FI_PWR_SMOOTHING_APPLIED_TMP_FLOOR,
+            NVML_FI_PWR_SMOOTHING_MAX_PERCENT_TMP_FLOOR_SETTING,
+            NVML_FI_PWR_SMOOTHING_MIN_PERCENT_TMP_FLOOR_SETTING,
+            NVML_FI_PWR_SMOOTHING_HW_CIRCUITRY_PERCENT_LIFETIME_REMAINING,
+            NVML_FI_PWR_SMOOTHING_MAX_NUM_PRESET_PROFILES,
+            NVML_FI_PWR_SMOOTHING_PROFILE_PERCENT_TMP_FLOOR,
+            NVML_FI_PWR_SMOOTHING_PROFILE_RAMP_UP_RATE,
+            NVML_FI_PWR_SMOOTHING_PROFILE_RAMP_DOWN_RATE,
+            NVML_FI_PWR_SMOOTHING_PROFILE_RAMP_DOWN_HYST_VAL,
+            NVML_FI_PWR_SMOOTHING_ACTIVE_PRESET_PROFILE,
+            NVML_FI_PWR_SMOOTHING_ADMIN_OVERRIDE_PERCENT_TMP_FLOOR,
+            NVML_FI_PWR_SMOOTHING_ADMIN_OVERRIDE_RAMP_UP_RATE,
+            NVML_FI_PWR_SMOOTHING_ADMIN_OVERRIDE_RAMP_DOWN_RATE,
+            NVML_FI_PWR_SMOOTHING_ADMIN_OVERRIDE_RAMP_DOWN_HYST_VAL,
+        )
+    }
+}
+
+/**
+A VBIOS version string, as returned from `Device.vbios_version()`.
+
+VBIOS versions are made up of fixed-width, dot-separated hex components (e.g.
+`"86.04.54.00.01"`), so they are ordered by comparing the raw strings
+lexically. The raw string is kept as-is.
+*/
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VbiosVersion(pub String);
+
+impl VbiosVersion {
+    /// The raw version string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for VbiosVersion {
+    fn from(version: String) -> Self {
+        Self(version)
+    }
+}
+
+impl std::fmt::Display for VbiosVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// Returned from `Device.mig_mode()`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
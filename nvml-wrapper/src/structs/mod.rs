@@ -1,2 +1,6 @@
+pub mod attestation;
 pub mod device;
 pub mod nv_link;
+pub mod system;
+
+pub use system::CudaDriverVersion;
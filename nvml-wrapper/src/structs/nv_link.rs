@@ -10,3 +10,32 @@ pub struct UtilizationCounter {
     /// Send counter value
     pub send: u64,
 }
+
+/// Returned by `Device.nvlink_throughput()`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NvLinkThroughput {
+    /// Data transmitted across all links, in KiB
+    pub tx_kib: u64,
+    /// Data received across all links, in KiB
+    pub rx_kib: u64,
+}
+
+/// Error counters for a single NvLink.
+///
+/// Returned by `Device.nvlink_error_summary()`. The counters are cumulative;
+/// diff two readings to see whether a link is degrading.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinkErrors {
+    /// The index of the link these counters are for.
+    pub link: u32,
+    /// Data link receive flow control digit CRC errors.
+    pub crc_flit: u64,
+    /// Data link receive data CRC errors.
+    pub crc_data: u64,
+    /// Data link transmit replay errors.
+    pub replay: u64,
+    /// Data link transmit recovery errors.
+    pub recovery: u64,
+}
@@ -0,0 +1,22 @@
+use crate::{cuda_driver_version_major, cuda_driver_version_minor};
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Returned from `Nvml.cuda_driver_version()`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CudaDriverVersion {
+    pub major: i32,
+    pub minor: i32,
+}
+
+impl From<i32> for CudaDriverVersion {
+    /// Splits a full CUDA driver version (e.g. `12030`) into its major and
+    /// minor parts (e.g. `12.3`).
+    fn from(version: i32) -> Self {
+        Self {
+            major: cuda_driver_version_major(version),
+            minor: cuda_driver_version_minor(version),
+        }
+    }
+}
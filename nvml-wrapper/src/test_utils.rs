@@ -1,5 +1,7 @@
 use crate::Device;
 use crate::GpmSample;
+#[cfg(target_os = "linux")]
+use crate::GpuInstance;
 use crate::NvLink;
 use crate::Nvml;
 use crate::Unit;
@@ -7,14 +9,19 @@ use crate::Unit;
 use crate::bitmasks::{device::*, event::*};
 
 use crate::enum_wrappers::device::*;
+use crate::enum_wrappers::nv_link::IntDeviceType;
 use crate::enums::device::BusType;
 use crate::enums::device::DeviceArchitecture;
 use crate::enums::device::PcieLinkMaxSpeed;
 use crate::enums::device::PowerSource;
+use crate::enums::device::ThermalTarget;
+use crate::enums::nv_link::NvLinkBwMode;
 use crate::enums::unit::*;
 use crate::error::NvmlError;
 use crate::event::EventSet;
+use crate::high_level::DeviceMetrics;
 use crate::struct_wrappers::gpm::GpmMetricResult;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
 use crate::struct_wrappers::nv_link::*;
@@ -22,7 +29,10 @@ use crate::struct_wrappers::{device::*, event::*, unit::*, *};
 
 use crate::structs::device::*;
 use crate::structs::nv_link::*;
+use crate::structs::CudaDriverVersion;
 
+#[cfg(target_os = "windows")]
+use crate::enums::device::DriverModelTransition;
 #[cfg(target_os = "windows")]
 use crate::structs::device::DriverModelState;
 
@@ -66,11 +76,15 @@ impl ShouldPrint for Vec<Result<GpmMetricResult, NvmlError>> {}
 
 impl ShouldPrint for bool {}
 impl ShouldPrint for u32 {}
+impl ShouldPrint for Option<u32> {}
+impl ShouldPrint for usize {}
 impl ShouldPrint for i32 {}
 impl ShouldPrint for (u32, u32) {}
 impl ShouldPrint for u64 {}
+impl ShouldPrint for f64 {}
 impl ShouldPrint for String {}
 impl ShouldPrint for Brand {}
+impl ShouldPrint for RecoveryAction {}
 impl ShouldPrint for [i8; 16] {}
 impl ShouldPrint for Vec<ProcessInfo> {}
 impl ShouldPrint for Vec<ProcessUtilizationSample> {}
@@ -79,8 +93,11 @@ impl<'nvml> ShouldPrint for Vec<Device<'nvml>> {}
 impl ShouldPrint for Vec<u32> {}
 impl ShouldPrint for Vec<u64> {}
 impl ShouldPrint for Vec<Sample> {}
+impl ShouldPrint for Vec<PowerSample> {}
+impl ShouldPrint for PcieUtilization {}
 impl ShouldPrint for Vec<Result<FieldValueSample, NvmlError>> {}
 impl ShouldPrint for Vec<HwbcEntry> {}
+impl ShouldPrint for Vec<DeviceMetrics> {}
 impl ShouldPrint for Utilization {}
 impl ShouldPrint for EncoderStats {}
 impl ShouldPrint for FbcStats {}
@@ -98,10 +115,16 @@ impl ShouldPrint for Vec<RetiredPage> {}
 impl ShouldPrint for ExcludedDeviceInfo {}
 impl ShouldPrint for MemoryInfo {}
 impl ShouldPrint for PciInfo {}
+impl ShouldPrint for PciInfoExt {}
+impl ShouldPrint for DynamicPstatesInfo {}
 impl ShouldPrint for PerformanceState {}
 impl ShouldPrint for PowerManagementConstraints {}
 impl ShouldPrint for ThrottleReasons {}
 impl ShouldPrint for ViolationTime {}
+impl ShouldPrint for Vec<LinkErrors> {}
+impl ShouldPrint for Vec<ThermalSensor> {}
+impl ShouldPrint for BTreeMap<ThermalTarget, i32> {}
+impl ShouldPrint for BTreeMap<u32, u64> {}
 impl ShouldPrint for AccountingStats {}
 impl ShouldPrint for EventTypes {}
 impl<'nvml> ShouldPrint for EventData<'nvml> {}
@@ -111,22 +134,51 @@ impl ShouldPrint for PsuInfo {}
 impl ShouldPrint for UnitInfo {}
 impl ShouldPrint for UtilizationControl {}
 impl ShouldPrint for UtilizationCounter {}
+impl ShouldPrint for ComputeInstanceProfileInfo {}
+impl ShouldPrint for GpuInstanceInfo {}
+impl ShouldPrint for ComputeInstanceInfo {}
+impl ShouldPrint for Vec<ComputeInstancePlacement> {}
+impl ShouldPrint for NvLinkThroughput {}
 impl ShouldPrint for BusType {}
 impl ShouldPrint for PowerSource {}
 impl ShouldPrint for DeviceArchitecture {}
 impl ShouldPrint for PcieLinkMaxSpeed {}
 impl ShouldPrint for DeviceAttributes {}
+impl ShouldPrint for C2cModeInfo {}
+impl ShouldPrint for NvLinkBwMode {}
+impl ShouldPrint for CudaDriverVersion {}
 impl ShouldPrint for (Vec<String>, u32) {}
 impl ShouldPrint for GpuVirtualizationMode {}
+impl ShouldPrint for HostVgpuMode {}
 impl ShouldPrint for ClockOffset {}
+impl ShouldPrint for Vec<ClockOffset> {}
 impl ShouldPrint for MigMode {}
 impl ShouldPrint for Vec<GpuInstancePlacement> {}
 impl ShouldPrint for (VgpuVersion, VgpuVersion) {}
+impl ShouldPrint for Vec<VgpuInstanceUtilizationInfo> {}
 impl ShouldPrint for ProfileInfo {}
 impl ShouldPrint for GspFirmwareMode {}
+impl ShouldPrint for ConfidentialComputeState {}
+impl ShouldPrint for ConfidentialComputeReadyState {}
+impl ShouldPrint for ConfidentialComputeKeyRotationThreshold {}
+impl ShouldPrint for VgpuMetadata {}
+impl ShouldPrint for VgpuPgpuMetadata {}
+impl ShouldPrint for VgpuPgpuCompatibility {}
+impl ShouldPrint for VgpuLicenseInfo {}
+impl ShouldPrint for CurrentClocks {}
+impl ShouldPrint for PerformanceSnapshot {}
+impl ShouldPrint for HealthSnapshot {}
+impl ShouldPrint for RemappedRows {}
+impl ShouldPrint for MemoryReport {}
+impl ShouldPrint for ThrottlingReport {}
+impl ShouldPrint for IntDeviceType {}
+impl ShouldPrint for GpuFabricInfo {}
+impl ShouldPrint for GridLicensableFeatures {}
 
 #[cfg(target_os = "windows")]
 impl ShouldPrint for DriverModelState {}
+#[cfg(target_os = "windows")]
+impl ShouldPrint for DriverModelTransition {}
 
 pub fn nvml() -> Nvml {
     Nvml::init().expect("initialized library")
@@ -175,6 +227,28 @@ where
     multi(reps, || test(&unit));
 }
 
+#[cfg(target_os = "linux")]
+pub fn test_with_gpu_instance<T, R>(reps: usize, nvml: &Nvml, test: T)
+where
+    T: Fn(&GpuInstance) -> Result<R, NvmlError>,
+    R: ShouldPrint,
+{
+    let device = device(nvml);
+    let profile = device
+        .profile_info(crate::ffi::bindings::NVML_GPU_INSTANCE_PROFILE_1_SLICE)
+        .expect("gpu instance profile info");
+    let gpu_instance = device
+        .gpu_instances(&profile)
+        .expect("gpu instances")
+        .into_iter()
+        .next()
+        .expect("a gpu instance");
+
+    single(|| test(&gpu_instance));
+
+    multi(reps, || test(&gpu_instance));
+}
+
 pub fn test_with_link<T, R>(reps: usize, nvml: &Nvml, test: T)
 where
     T: Fn(&NvLink) -> Result<R, NvmlError>,
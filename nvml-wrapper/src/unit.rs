@@ -104,7 +104,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested
     #[doc(alias = "nvmlUnitGetDevices")]
     pub fn devices(&self) -> Result<Vec<Device<'_>>, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetDevices.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetDevices.as_ref(),
+            "nvmlUnitGetDevices",
+        )?;
 
         unsafe {
             let mut count: c_uint = match self.device_count()? {
@@ -143,7 +146,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested as part of the above
     #[doc(alias = "nvmlUnitGetDevices")]
     pub fn device_count(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetDevices.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetDevices.as_ref(),
+            "nvmlUnitGetDevices",
+        )?;
 
         /*
         From the docs:
@@ -177,7 +183,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested
     #[doc(alias = "nvmlUnitGetFanSpeedInfo")]
     pub fn fan_info(&self) -> Result<FansInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetFanSpeedInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetFanSpeedInfo.as_ref(),
+            "nvmlUnitGetFanSpeedInfo",
+        )?;
 
         unsafe {
             let mut fans_info: nvmlUnitFanSpeeds_t = mem::zeroed();
@@ -206,7 +215,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested
     #[doc(alias = "nvmlUnitGetLedState")]
     pub fn led_state(&self) -> Result<LedState, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetLedState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetLedState.as_ref(),
+            "nvmlUnitGetLedState",
+        )?;
 
         unsafe {
             let mut state: nvmlLedState_t = mem::zeroed();
@@ -235,7 +247,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested
     #[doc(alias = "nvmlUnitGetPsuInfo")]
     pub fn psu_info(&self) -> Result<PsuInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetPsuInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetPsuInfo.as_ref(),
+            "nvmlUnitGetPsuInfo",
+        )?;
         unsafe {
             let mut info: nvmlPSUInfo_t = mem::zeroed();
             nvml_try(sym(self.unit, &mut info))?;
@@ -264,7 +279,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested
     #[doc(alias = "nvmlUnitGetTemperature")]
     pub fn temperature(&self, reading_type: TemperatureReading) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetTemperature.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetTemperature.as_ref(),
+            "nvmlUnitGetTemperature",
+        )?;
 
         unsafe {
             let mut temp: c_uint = mem::zeroed();
@@ -292,7 +310,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested
     #[doc(alias = "nvmlUnitGetUnitInfo")]
     pub fn info(&self) -> Result<UnitInfo, NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitGetUnitInfo.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitGetUnitInfo.as_ref(),
+            "nvmlUnitGetUnitInfo",
+        )?;
 
         unsafe {
             let mut info: nvmlUnitInfo_t = mem::zeroed();
@@ -329,7 +350,10 @@ impl<'nvml> Unit<'nvml> {
     // Tested (no-run)
     #[doc(alias = "nvmlUnitSetLedState")]
     pub fn set_led_color(&mut self, color: LedColor) -> Result<(), NvmlError> {
-        let sym = nvml_sym(self.nvml.lib.nvmlUnitSetLedState.as_ref())?;
+        let sym = nvml_sym(
+            self.nvml.lib.nvmlUnitSetLedState.as_ref(),
+            "nvmlUnitSetLedState",
+        )?;
 
         unsafe { nvml_try(sym(self.unit, color.as_c())) }
     }
@@ -1,16 +1,26 @@
 use std::{ffi::CStr, os::raw::c_uint};
 
 use ffi::bindings::{
-    nvmlVgpuCapability_t, nvmlVgpuTypeId_t, NVML_DEVICE_NAME_BUFFER_SIZE,
-    NVML_GRID_LICENSE_BUFFER_SIZE,
+    nvmlDevice_t, nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE, nvmlReturn_t, nvmlVgpuCapability_t,
+    nvmlVgpuPlacementList_t, nvmlVgpuPlacementList_v2_t, nvmlVgpuTypeId_t,
+    nvmlVgpuTypeMaxInstance_t, nvmlVgpuTypeMaxInstance_v1_t, NVML_DEVICE_NAME_BUFFER_SIZE,
+    NVML_GRID_LICENSE_BUFFER_SIZE, NVML_VGPU_PGPU_HETEROGENEOUS_MODE,
+    NVML_VGPU_PGPU_HOMOGENEOUS_MODE,
 };
 use static_assertions::assert_impl_all;
 
 use crate::{
     error::{nvml_sym, nvml_try, NvmlError},
+    struct_wrappers::device::VgpuPlacementList,
     Device,
 };
 
+type PlacementsSym = unsafe extern "C" fn(
+    nvmlDevice_t,
+    nvmlVgpuTypeId_t,
+    *mut nvmlVgpuPlacementList_t,
+) -> nvmlReturn_t;
+
 pub struct VgpuType<'dev> {
     id: nvmlVgpuTypeId_t,
     device: &'dev Device<'dev>,
@@ -51,7 +61,10 @@ impl<'dev> VgpuType<'dev> {
     /// Kepler or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetClass")]
     pub fn class_name(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetClass.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetClass.as_ref(),
+            "nvmlVgpuTypeGetClass",
+        )?;
 
         unsafe {
             let mut size = NVML_DEVICE_NAME_BUFFER_SIZE;
@@ -84,7 +97,10 @@ impl<'dev> VgpuType<'dev> {
     /// Kepler or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetLicense")]
     pub fn license(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetLicense.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetLicense.as_ref(),
+            "nvmlVgpuTypeGetLicense",
+        )?;
 
         unsafe {
             let mut buffer = vec![0; NVML_GRID_LICENSE_BUFFER_SIZE as usize];
@@ -111,7 +127,10 @@ impl<'dev> VgpuType<'dev> {
     /// Kepler or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetName")]
     pub fn name(&self) -> Result<String, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetName.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetName.as_ref(),
+            "nvmlVgpuTypeGetName",
+        )?;
 
         unsafe {
             let mut size = NVML_DEVICE_NAME_BUFFER_SIZE;
@@ -139,7 +158,10 @@ impl<'dev> VgpuType<'dev> {
     /// Maxwell or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetCapabilities")]
     pub fn capabilities(&self, capability: nvmlVgpuCapability_t) -> Result<bool, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetCapabilities.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetCapabilities.as_ref(),
+            "nvmlVgpuTypeGetCapabilities",
+        )?;
 
         let mut result: c_uint = 0;
         unsafe {
@@ -161,7 +183,10 @@ impl<'dev> VgpuType<'dev> {
     /// Kepler or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetDeviceID")]
     pub fn device_id(&self) -> Result<(u64, u64), NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetDeviceID.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetDeviceID.as_ref(),
+            "nvmlVgpuTypeGetDeviceID",
+        )?;
 
         let (mut device_id, mut subsystem_id) = (0, 0);
         unsafe {
@@ -190,6 +215,7 @@ impl<'dev> VgpuType<'dev> {
                 .lib
                 .nvmlVgpuTypeGetFrameRateLimit
                 .as_ref(),
+            "nvmlVgpuTypeGetFrameRateLimit",
         )?;
 
         let mut limit = 0;
@@ -218,6 +244,7 @@ impl<'dev> VgpuType<'dev> {
                 .lib
                 .nvmlVgpuTypeGetFramebufferSize
                 .as_ref(),
+            "nvmlVgpuTypeGetFramebufferSize",
         )?;
 
         let mut size = 0;
@@ -248,6 +275,7 @@ impl<'dev> VgpuType<'dev> {
                 .lib
                 .nvmlVgpuTypeGetGpuInstanceProfileId
                 .as_ref(),
+            "nvmlVgpuTypeGetGpuInstanceProfileId",
         )?;
 
         let mut profile_id = 0;
@@ -270,7 +298,10 @@ impl<'dev> VgpuType<'dev> {
     /// Kepler or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetMaxInstances")]
     pub fn max_instances(&self) -> Result<u32, NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetMaxInstances.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetMaxInstances.as_ref(),
+            "nvmlVgpuTypeGetMaxInstances",
+        )?;
 
         let mut max = 0;
         unsafe {
@@ -298,6 +329,7 @@ impl<'dev> VgpuType<'dev> {
                 .lib
                 .nvmlVgpuTypeGetMaxInstancesPerVm
                 .as_ref(),
+            "nvmlVgpuTypeGetMaxInstancesPerVm",
         )?;
 
         let mut max = 0;
@@ -307,6 +339,149 @@ impl<'dev> VgpuType<'dev> {
         Ok(max)
     }
 
+    /// Retrieve the maximum number of vGPU instances of this type that can be
+    /// created per GPU instance.
+    ///
+    /// This only applies to MIG-backed vGPU types.
+    ///
+    /// # Errors
+    ///
+    /// * `Uninitialized`, if the library has not been successfully initialized
+    /// * `InvalidArg`, if this vGPU type is invalid
+    /// * `NotSupported`, if not on a vGPU host, on an unsupported GPU or for a
+    ///   non-MIG vGPU type
+    /// * `Unknown`, on any unexpected error
+    #[doc(alias = "nvmlVgpuTypeGetMaxInstancesPerGpuInstance")]
+    pub fn max_instances_per_gpu_instance(&self) -> Result<u32, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlVgpuTypeGetMaxInstancesPerGpuInstance
+                .as_ref(),
+            "nvmlVgpuTypeGetMaxInstancesPerGpuInstance",
+        )?;
+
+        unsafe {
+            let mut max: nvmlVgpuTypeMaxInstance_t = std::mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(VgpuTypeMaxInstance, 1), as detailed in nvml.h
+            max.version = (std::mem::size_of::<nvmlVgpuTypeMaxInstance_v1_t>()
+                | (1_usize << 24_usize)) as u32;
+            max.vgpuTypeId = self.id;
+
+            nvml_try(sym(&mut max))?;
+
+            Ok(max.maxInstancePerGI)
+        }
+    }
+
+    /// Retrieve the placements supported by this vGPU type on its device.
+    ///
+    /// Pass `true` for `heterogeneous` to get the placement IDs that apply when
+    /// the device is in heterogeneous mode (see
+    /// [`Device::vgpu_heterogeneous_mode`]), or `false` for homogeneous mode.
+    ///
+    /// # Errors
+    ///
+    /// * `Uninitialized`, if the library has not been successfully initialized
+    /// * `InvalidArg`, if this `Device` or vGPU type is invalid
+    /// * `NotSupported`, if this `Device` or vGPU type isn't supported
+    /// * `NoPermission`, if the user doesn't have permission to perform this operation
+    /// * `Unknown`, on any unexpected error
+    #[doc(alias = "nvmlDeviceGetVgpuTypeSupportedPlacements")]
+    pub fn supported_placements(
+        &self,
+        heterogeneous: bool,
+    ) -> Result<VgpuPlacementList, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlDeviceGetVgpuTypeSupportedPlacements
+                .as_ref(),
+            "nvmlDeviceGetVgpuTypeSupportedPlacements",
+        )?;
+
+        self.placements(sym, heterogeneous)
+    }
+
+    /// Retrieve the placements at which a vGPU of this type can currently be
+    /// created on its device.
+    ///
+    /// The creatable placements may change over time depending on which vGPU
+    /// instances are running.
+    ///
+    /// Pass `true` for `heterogeneous` to get the placement IDs that apply when
+    /// the device is in heterogeneous mode (see
+    /// [`Device::vgpu_heterogeneous_mode`]), or `false` for homogeneous mode.
+    ///
+    /// # Errors
+    ///
+    /// * `Uninitialized`, if the library has not been successfully initialized
+    /// * `InvalidArg`, if this `Device` or vGPU type is invalid
+    /// * `NotSupported`, if MIG is enabled or this `Device` or vGPU type isn't supported
+    /// * `NoPermission`, if the user doesn't have permission to perform this operation
+    /// * `Unknown`, on any unexpected error
+    #[doc(alias = "nvmlDeviceGetVgpuTypeCreatablePlacements")]
+    pub fn creatable_placements(
+        &self,
+        heterogeneous: bool,
+    ) -> Result<VgpuPlacementList, NvmlError> {
+        let sym = nvml_sym(
+            self.device
+                .nvml()
+                .lib
+                .nvmlDeviceGetVgpuTypeCreatablePlacements
+                .as_ref(),
+            "nvmlDeviceGetVgpuTypeCreatablePlacements",
+        )?;
+
+        self.placements(sym, heterogeneous)
+    }
+
+    // Removes code duplication in the above functions.
+    fn placements(
+        &self,
+        sym: &PlacementsSym,
+        heterogeneous: bool,
+    ) -> Result<VgpuPlacementList, NvmlError> {
+        // NVML says the buffer should be sized based on the max instance count;
+        // it tells us the required size if that's not enough.
+        let mut ids: Vec<c_uint> = vec![0; self.max_instances()? as usize];
+
+        unsafe {
+            let mut list: nvmlVgpuPlacementList_t = std::mem::zeroed();
+            // Implements NVML_STRUCT_VERSION(VgpuPlacementList, 2), as detailed in nvml.h
+            list.version =
+                (std::mem::size_of::<nvmlVgpuPlacementList_v2_t>() | (2_usize << 24_usize)) as u32;
+            list.mode = if heterogeneous {
+                NVML_VGPU_PGPU_HETEROGENEOUS_MODE
+            } else {
+                NVML_VGPU_PGPU_HOMOGENEOUS_MODE
+            };
+            list.count = ids.len() as c_uint;
+            list.placementIds = ids.as_mut_ptr();
+
+            let mut result = sym(self.device.handle(), self.id, &mut list);
+
+            if result == nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE {
+                ids.resize(list.count as usize, 0);
+                list.placementIds = ids.as_mut_ptr();
+
+                result = sym(self.device.handle(), self.id, &mut list);
+            }
+
+            nvml_try(result)?;
+
+            ids.truncate(list.count as usize);
+
+            Ok(VgpuPlacementList {
+                placement_size: list.placementSize,
+                placement_ids: ids,
+            })
+        }
+    }
+
     /// Retrieve count of vGPU's supported display heads.
     ///
     /// # Errors
@@ -326,6 +501,7 @@ impl<'dev> VgpuType<'dev> {
                 .lib
                 .nvmlVgpuTypeGetNumDisplayHeads
                 .as_ref(),
+            "nvmlVgpuTypeGetNumDisplayHeads",
         )?;
 
         let mut heads = 0;
@@ -351,7 +527,10 @@ impl<'dev> VgpuType<'dev> {
     /// Kepler or newer fully supported devices.
     #[doc(alias = "nvmlVgpuTypeGetResolution")]
     pub fn resolution(&self, display_head: u32) -> Result<(u32, u32), NvmlError> {
-        let sym = nvml_sym(self.device.nvml().lib.nvmlVgpuTypeGetResolution.as_ref())?;
+        let sym = nvml_sym(
+            self.device.nvml().lib.nvmlVgpuTypeGetResolution.as_ref(),
+            "nvmlVgpuTypeGetResolution",
+        )?;
 
         let (mut x, mut y) = (0, 0);
         unsafe {